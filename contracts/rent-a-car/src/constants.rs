@@ -0,0 +1,76 @@
+/// One hour of ledger time, in seconds.
+pub const SECONDS_PER_HOUR: u64 = 3_600;
+
+/// Length of one rental day, in seconds of ledger time.
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// Denominator for basis-point percentages (750 bps = 7.5%).
+pub const BASIS_POINTS_DENOMINATOR: i128 = 10_000;
+
+/// Time an owner has after a return to inspect the car before the deposit can be released.
+pub const DEFAULT_INSPECTION_WINDOW: u64 = SECONDS_PER_DAY;
+
+/// Longest accepted make or model name, in bytes.
+pub const MAX_CAR_NAME_LENGTH: u32 = 32;
+
+/// Longest accepted image URI, in bytes.
+pub const MAX_IMAGE_URI_LENGTH: u32 = 256;
+
+/// Earliest model year accepted for a listed car.
+pub const MIN_CAR_YEAR: u32 = 1886;
+
+/// Largest seat count accepted for a listed car.
+pub const MAX_CAR_SEATS: u32 = 64;
+
+/// Largest number of cars returned by a single catalog page.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// Most reservations a single car can hold before they start.
+pub const MAX_BOOKINGS_PER_CAR: u32 = 20;
+
+/// Most upcoming reservations one renter can hold on the same car.
+pub const MAX_BOOKINGS_PER_RENTER: u32 = 3;
+
+/// How long before a reservation starts it can still be cancelled for a full refund.
+pub const DEFAULT_FULL_REFUND_WINDOW: u64 = SECONDS_PER_DAY;
+
+/// Share of the price refunded for cancellations made after the full-refund window has passed
+/// but before the reservation starts.
+pub const DEFAULT_PARTIAL_REFUND_BPS: u32 = 5_000;
+
+/// Time past the due time after which an owner or admin may reclaim an unreturned car.
+pub const DEFAULT_RECLAIM_GRACE_PERIOD: u64 = SECONDS_PER_DAY;
+
+/// Time after a handoff rental starts for the owner to hand the car over before anyone may cancel it,
+/// and after the handover for the renter to confirm it before anyone may start the rental.
+pub const PICKUP_TIMEOUT: u64 = SECONDS_PER_DAY;
+
+/// Time the owner has to confirm a handed-back car before anyone may settle the return.
+pub const RETURN_CONFIRMATION_TIMEOUT: u64 = SECONDS_PER_DAY;
+
+/// Highest fuel reading accepted at handover or return, as a percentage of the tank.
+pub const MAX_FUEL_LEVEL: u32 = 100;
+
+/// Ledgers closed in one day, at roughly five seconds per ledger.
+pub const DAY_IN_LEDGERS: u32 = 17_280;
+
+/// TTL, in ledgers, that cars, rentals and other per-entity entries are topped up to when touched.
+pub const PERSISTENT_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Remaining TTL below which a touched entry is extended, so busy entries are not bumped on every call.
+pub const PERSISTENT_LIFETIME_THRESHOLD: u32 = PERSISTENT_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// TTL, in ledgers, that the contract instance and its code are topped up to when the contract is used.
+pub const INSTANCE_BUMP_AMOUNT: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Remaining instance TTL below which it is extended.
+pub const INSTANCE_LIFETIME_THRESHOLD: u32 = INSTANCE_BUMP_AMOUNT - DAY_IN_LEDGERS;
+
+/// How long a proposed admin has to accept the role before the proposal lapses.
+pub const ADMIN_PROPOSAL_EXPIRY: u64 = 7 * SECONDS_PER_DAY;
+
+/// Storage schema version of deployments that never recorded one.
+pub const LEGACY_SCHEMA_VERSION: u32 = 1;
+
+/// Storage schema version written by this build; `migrate` brings older state up to it.
+pub const CURRENT_SCHEMA_VERSION: u32 = 2;
@@ -1,16 +1,66 @@
 ﻿use crate::{events, methods};
+use crate::constants::{ADMIN_PROPOSAL_EXPIRY, BASIS_POINTS_DENOMINATOR, CURRENT_SCHEMA_VERSION, MAX_BOOKINGS_PER_CAR, MAX_BOOKINGS_PER_RENTER, MAX_FUEL_LEVEL, PICKUP_TIMEOUT, RETURN_CONFIRMATION_TIMEOUT, SECONDS_PER_DAY};
 use crate::interfaces::contract::RentACarContractTrait;
+use crate::methods::access::car_manager::require_owner_or_admin;
+use crate::methods::access::pause::ensure_not_paused;
+use crate::methods::access::role::require_admin_or_role;
+use crate::methods::booking::calendar::{bookings_in_window, ensure_window_is_free};
+use crate::methods::booking::settle::{cancellation_refund_bps, settle_unstarted_booking};
+use crate::methods::car::metadata::validate_car_metadata;
+use crate::methods::deposit::release::release_deposit_to_renter;
+use crate::methods::deposit::settle::settle_deposit;
+use crate::methods::pricing::car_price::car_price_in;
+use crate::methods::pricing::commission::{commission_config_in, validate_commission};
+use crate::methods::pricing::rental_quote::compute_rental_quote;
+use crate::methods::rental::close::{charge_late_fee, close_rental, hand_back_deposit, settle_handoff_return};
+use crate::methods::rental::history::{record_rental_closed, record_rental_extended};
+use crate::methods::rental::open::{allocate_rental_id, complete_pickup, credit_commission, open_rental};
+use crate::methods::token::accepted::ensure_token_accepted;
 use crate::methods::token::token::token_transfer;
-use crate::storage::admin::{has_admin, read_admin, write_admin, read_admin_commission, write_admin_commission, read_admin_available_to_withdraw, write_admin_available_to_withdraw};
-use crate::storage::car::{has_car, read_car, remove_car, write_car};
+use crate::storage::admin::{has_admin, read_admin, read_pending_admin, remove_admin, remove_pending_admin, write_admin, write_pending_admin, write_admin_commission, read_admin_available_to_withdraw, write_admin_available_to_withdraw, read_treasury, write_treasury, write_token_commission};
+use crate::storage::arbiter::{read_arbiter, write_arbiter};
+use crate::storage::booking::{read_booking, read_car_bookings, read_next_booking_id, remove_car_booking, write_booking, write_car_bookings, write_next_booking_id};
+use crate::storage::cancellation::{read_cancellation_policy, write_cancellation_policy};
+use crate::storage::car::{has_car, is_car_owner, read_car, read_next_car_id, record_car_owner, write_car, write_car_metadata, write_next_car_id};
 use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
-use crate::storage::rental::{write_rental, remove_rental, has_rental};
+use crate::storage::damage_claim::{has_damage_claim, read_damage_claim, write_damage_claim};
+use crate::storage::deposit::{has_deposit, read_deposit, read_deposit_balance, write_deposit, write_deposit_balance, write_inspection_window};
+use crate::storage::handoff::{has_handoff, read_handoff, read_handoff_required, write_handoff, write_handoff_required};
+use crate::storage::early_return::{read_early_return_policy, remove_early_return_policy, write_early_return_policy};
+use crate::storage::late_fee::{read_late_fee_platform_share, read_late_fee_policy, remove_late_fee_policy, write_late_fee_platform_share, write_late_fee_policy};
+use crate::storage::pause::{read_paused, write_paused};
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::rental::{read_rental, read_reclaim_grace_period, has_rental, remove_rental, write_reclaim_grace_period, write_rental};
+use crate::storage::rental_history::read_rental_record;
+use crate::storage::role::{has_role, remove_role, write_role};
+use crate::storage::schema::{read_schema_version, write_schema_version};
+use crate::storage::structs::booking::Booking;
+use crate::storage::structs::cancellation_policy::CancellationPolicy;
 use crate::storage::structs::car::Car;
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::structs::car_metadata::CarMetadata;
+use crate::storage::structs::car_token_price::CarTokenPrice;
+use crate::storage::structs::commission::CommissionConfig;
+use crate::storage::structs::damage_claim::DamageClaim;
+use crate::storage::structs::deposit::Deposit;
+use crate::storage::structs::early_return_policy::EarlyReturnPolicy;
+use crate::storage::structs::handoff::Handoff;
+use crate::storage::structs::legacy::LegacyCarRef;
+use crate::storage::structs::late_fee_policy::LateFeePolicy;
+use crate::storage::structs::pending_admin::PendingAdmin;
 use crate::storage::structs::rental::Rental;
-use crate::storage::token::write_token;
+use crate::storage::structs::rental_record::RentalRecord;
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::token::{is_token_allowed, read_allowed_tokens, read_booking_token, read_car_token_price, read_car_tokens, read_rental_token, read_token, write_allowed_tokens, write_booking_token, write_car_token_price, write_car_tokens, write_rental_token, write_token};
+use crate::storage::types::booking_status::BookingStatus;
 use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::claim_status::ClaimStatus;
+use crate::storage::types::commission_mode::CommissionMode;
+use crate::storage::types::deposit_status::DepositStatus;
 use crate::storage::types::errors::Error;
-use soroban_sdk::{contract, contractimpl, Address, Env};
+use crate::storage::types::rental_outcome::RentalOutcome;
+use crate::storage::types::role::Role;
+use soroban_sdk::{contract, contractimpl, token, Address, BytesN, Env, Vec};
 use crate::methods::public;
 use crate::methods::public::get_owner_available_to_withdraw as get_owner_available_to_withdraw_public;
 
@@ -25,61 +75,270 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::AdminTokenConflict);
         }
 
-        if has_admin(&env) {
+        if has_admin(env) {
             return Err(Error::ContractInitialized);
         }
 
         write_admin(env, &admin);
         write_token(env, &token);
+        write_schema_version(env, CURRENT_SCHEMA_VERSION);
 
         events::contract::contract_initialized(env, admin, token);
 
         Ok(())
     }
 
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error> {
+    fn get_admin(env: &Env) -> Result<Address, Error> {
+        read_admin(env)
+    }
+
+    fn get_pending_admin(env: &Env) -> Option<PendingAdmin> {
+        read_pending_admin(env)
+    }
+
+    fn propose_admin(env: &Env, new_admin: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if new_admin == read_token(env)? {
+            return Err(Error::AdminTokenConflict);
+        }
+
+        if read_arbiter(env).is_ok_and(|arbiter| arbiter == new_admin) {
+            return Err(Error::InvalidArbiter);
+        }
+
+        let expires_ts = env.ledger()
+            .timestamp()
+            .checked_add(ADMIN_PROPOSAL_EXPIRY)
+            .ok_or(Error::OverflowError)?;
+
+        // A new proposal replaces any earlier one that was never accepted
+        write_pending_admin(env, &PendingAdmin { admin: new_admin.clone(), expires_ts });
+        events::admin::admin_proposed(env, admin, new_admin, expires_ts);
+        Ok(())
+    }
+
+    fn accept_admin(env: &Env) -> Result<(), Error> {
+        let pending_admin = read_pending_admin(env).ok_or(Error::AdminProposalNotFound)?;
+        pending_admin.admin.require_auth();
+
+        let old_admin = read_admin(env)?;
+
+        write_admin(env, &pending_admin.admin);
+        remove_pending_admin(env);
+        events::admin::admin_transferred(env, old_admin, pending_admin.admin);
+        Ok(())
+    }
+
+    fn renounce_admin(env: &Env) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Admin-only functions stop working for good; owners keep managing their own cars
+        remove_admin(env);
+        remove_pending_admin(env);
+        events::admin::admin_renounced(env, admin);
+        Ok(())
+    }
+
+    fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // The new code takes over once this call returns; run `migrate` next if its schema changed
+        env.deployer().update_current_contract_wasm(new_wasm_hash.clone());
+        events::contract::contract_upgraded(env, admin, new_wasm_hash);
+        Ok(())
+    }
+
+    fn migrate(env: &Env) -> Result<u32, Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        methods::storage::schema::migrate_schema(env)
+    }
+
+    fn get_schema_version(env: &Env) -> u32 {
+        read_schema_version(env)
+    }
+
+    fn grant_role(env: &Env, account: Address, role: Role) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_role(env, role.clone(), &account);
+        events::role::role_granted(env, role, account);
+        Ok(())
+    }
+
+    fn revoke_role(env: &Env, account: Address, role: Role) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        remove_role(env, role.clone(), &account);
+        events::role::role_revoked(env, role, account);
+        Ok(())
+    }
+
+    fn has_role(env: &Env, account: Address, role: Role) -> bool {
+        has_role(env, role, &account)
+    }
+
+    fn pause(env: &Env, caller: Address) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Pauser)?;
+
+        write_paused(env, true);
+        events::pause::paused(env, caller);
+        Ok(())
+    }
+
+    fn unpause(env: &Env, caller: Address) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Pauser)?;
+
+        write_paused(env, false);
+        events::pause::unpaused(env, caller);
+        Ok(())
+    }
+
+    fn is_paused(env: &Env) -> bool {
+        read_paused(env)
+    }
+
+    fn allow_token(env: &Env, token: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Anything that does not answer the token interface would only fail later, at the first payment
+        if !matches!(token::TokenClient::new(env, &token).try_decimals(), Ok(Ok(_))) {
+            return Err(Error::TokenNotFound);
+        }
+
+        let mut tokens = read_allowed_tokens(env)?;
+
+        if !tokens.contains(&token) {
+            tokens.push_back(token.clone());
+            write_allowed_tokens(env, &tokens);
+        }
+
+        events::token::token_allowed(env, token);
+        Ok(())
+    }
+
+    fn disallow_token(env: &Env, token: Address) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
+        let mut tokens = read_allowed_tokens(env)?;
+
+        // Balances already held in the token stay withdrawable; only new payments are refused
+        if let Some(index) = tokens.first_index_of(&token) {
+            tokens.remove(index);
+            write_allowed_tokens(env, &tokens);
+        }
+
+        events::token::token_disallowed(env, token);
+        Ok(())
+    }
+
+    fn get_allowed_tokens(env: &Env) -> Result<Vec<Address>, Error> {
+        read_allowed_tokens(env)
+    }
+
+    fn add_car(env: &Env, caller: Address, owner: Address, price_per_day: i128, metadata: CarMetadata) -> Result<u32, Error> {
+        require_admin_or_role(env, &caller, Role::FleetManager)?;
+
         if price_per_day <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        if has_car(env, &owner) {
-            return Err(Error::CarAlreadyExist);
+        validate_car_metadata(&metadata)?;
+
+        // The arbiter settles disputes between owners and renters, so it cannot be either
+        if read_arbiter(env).is_ok_and(|arbiter| arbiter == owner) {
+            return Err(Error::InvalidArbiter);
         }
 
+        let car_id = read_next_car_id(env);
+        let next_car_id = car_id.checked_add(1).ok_or(Error::OverflowError)?;
+
         let car = Car {
+            owner: owner.clone(),
             price_per_day,
             car_status: CarStatus::Available,
-            available_to_withdraw: 0,
+            deposit_amount: 0,
         };
 
-        write_car(env, &owner, &car);
-        events::add_car::car_added(env, owner, price_per_day);
-        Ok(())
+        write_car(env, car_id, &car);
+        write_car_metadata(env, car_id, &metadata);
+        write_next_car_id(env, next_car_id);
+        record_car_owner(env, &owner)?;
+        events::add_car::car_added(env, owner, car_id, price_per_day, metadata);
+        Ok(car_id)
+    }
+
+    fn get_car(env: &Env, car_id: u32) -> Result<CarDetails, Error> {
+        public::get_car::get_car(env, car_id)
+    }
+
+    fn get_car_status(env: &Env, car_id: u32) -> Result<CarStatus, Error> {
+        public::get_car_status::get_car_status(env, car_id)
+    }
+
+    fn get_car_count(env: &Env) -> u32 {
+        public::list_cars::get_car_count(env)
+    }
+
+    fn list_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error> {
+        public::list_cars::list_cars(env, start, limit)
     }
 
-    fn get_car_status(env: &Env, owner: Address) -> Result<CarStatus, Error> {
-        public::get_car_status::get_car_status(env, &owner)
+    fn list_available_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error> {
+        public::list_cars::list_available_cars(env, start, limit)
     }
 
-    fn get_admin_available_to_withdraw(env: &Env) -> i128 {
-        public::get_admin_available_to_withdraw::get_admin_available_to_withdraw(env)
+    fn get_admin_available_to_withdraw(env: &Env, token: Address) -> i128 {
+        public::get_admin_available_to_withdraw::get_admin_available_to_withdraw(env, &token)
     }
 
-    fn get_owner_available_to_withdraw(env: &Env, owner: Address) -> i128 {
-        get_owner_available_to_withdraw_public::get_owner_available_to_withdraw(env, &owner)
+    fn get_owner_available_to_withdraw(env: &Env, owner: Address, token: Address) -> i128 {
+        get_owner_available_to_withdraw_public::get_owner_available_to_withdraw(env, &owner, &token)
+    }
+
+    fn get_active_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
+        public::get_rental::get_active_rental(env, car_id)
+    }
+
+    fn get_rental(env: &Env, rental_id: u32) -> Result<RentalRecord, Error> {
+        public::get_rental::get_rental(env, rental_id)
+    }
+
+    fn get_rental_token(env: &Env, rental_id: u32) -> Result<Address, Error> {
+        public::get_rental::get_rental_token(env, rental_id)
+    }
+
+    fn list_rentals_by_renter(env: &Env, renter: Address, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error> {
+        public::list_rentals::list_rentals_by_renter(env, &renter, start, limit)
+    }
+
+    fn list_rentals_by_car(env: &Env, car_id: u32, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error> {
+        public::list_rentals::list_rentals_by_car(env, car_id, start, limit)
+    }
+
+    fn quote_rental(env: &Env, car_id: u32, total_days_to_rent: u32, token: Address) -> Result<RentalQuote, Error> {
+        public::quote_rental::quote_rental(env, car_id, total_days_to_rent, token)
     }
 
     fn rental(
         env: &Env,
         renter: Address,
-        owner: Address,
+        car_id: u32,
         total_days_to_rent: u32,
         amount: i128,
+        token: Address,
     ) -> Result<(), Error> {
         renter.require_auth();
+        ensure_not_paused(env)?;
 
         if amount <= 0 {
             return Err(Error::AmountMustBePositive);
@@ -89,189 +348,1400 @@ impl RentACarContractTrait for RentACarContract {
             return Err(Error::RentalDurationCannotBeZero);
         }
 
-        if renter == owner {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if renter == car.owner {
             return Err(Error::SelfRentalNotAllowed);
         }
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarUnderMaintenance);
         }
 
-        let mut car = read_car(env, &owner)?;
+        if car.car_status == CarStatus::Delisted {
+            return Err(Error::CarDelisted);
+        }
 
         if car.car_status != CarStatus::Available {
             return Err(Error::CarAlreadyRented);
         }
 
-        car.car_status = CarStatus::Rented;
-        
-        let admin_commission = read_admin_commission(env);
-        let total_amount = amount
-            .checked_add(admin_commission)
-            .ok_or(Error::OverflowError)?;
+        ensure_token_accepted(env, car_id, &token)?;
 
-        // Owner receives the full rental amount (without commission deduction)
-        car.available_to_withdraw = car
-            .available_to_withdraw
-            .checked_add(amount)
+        let quote = compute_rental_quote(env, car_id, &car, &token, total_days_to_rent)?;
+
+        // Renter pays the rental price and commission, plus the car's refundable deposit
+        let total_payment = quote.total
+            .checked_add(quote.deposit)
             .ok_or(Error::OverflowError)?;
 
-        // Accumulate commission for admin
-        let mut admin_available = read_admin_available_to_withdraw(env);
-        admin_available = admin_available
-            .checked_add(admin_commission)
+        // `amount` is the most the renter agreed to pay; the price itself comes from the car
+        if amount < total_payment {
+            return Err(Error::InsufficientPayment);
+        }
+
+        let start_ts = env.ledger().timestamp();
+        let rental_duration = (total_days_to_rent as u64)
+            .checked_mul(SECONDS_PER_DAY)
             .ok_or(Error::OverflowError)?;
-        write_admin_available_to_withdraw(env, admin_available);
+        let due_ts = start_ts
+            .checked_add(rental_duration)
+            .ok_or(Error::OverflowError)?;
+
+        // Walk-in rentals must not run into an upcoming reservation
+        ensure_window_is_free(env, car_id, start_ts, due_ts)?;
 
         let rental = Rental {
+            rental_id: allocate_rental_id(env)?,
+            renter: renter.clone(),
             total_days_to_rent,
-            amount,
+            amount: quote.base_price,
+            deposit: quote.deposit,
+            start_ts,
+            due_ts,
         };
 
-        let mut contract_balance = read_contract_balance(&env);
+        let mut contract_balance = read_contract_balance(env, &token);
         // Contract balance includes both the rental amount and commission
         contract_balance = contract_balance
-            .checked_add(total_amount)
+            .checked_add(quote.total)
             .ok_or(Error::OverflowError)?;
 
-        write_contract_balance(&env, &contract_balance);
-        write_car(env, &owner, &car);
-        write_rental(env, &renter, &owner, &rental);
+        write_contract_balance(env, &token, &contract_balance);
+        write_rental_token(env, rental.rental_id, &token);
+        open_rental(env, car_id, &mut car, &rental, quote.commission)?;
+        credit_commission(env, &car, &token, quote.commission)?;
 
-        // Renter pays the rental amount plus commission
-        token_transfer(&env, &renter, &env.current_contract_address(), &total_amount)?;
-        events::rental::rented(env, renter, owner, total_days_to_rent, amount);
-        Ok(())
-    }
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error> {
-        let admin = read_admin(env)?;
-        admin.require_auth();
+        if quote.deposit > 0 {
+            let deposit_balance = read_deposit_balance(env, &token)
+                .checked_add(quote.deposit)
+                .ok_or(Error::OverflowError)?;
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
+            write_deposit_balance(env, &token, &deposit_balance);
         }
 
-        remove_car(env, &owner);
-        events::remove_car::car_removed(env, owner);
+        token_transfer(env, &token, &renter, &env.current_contract_address(), &total_payment)?;
+        events::rental::rented(env, renter.clone(), car_id, total_days_to_rent, quote.base_price, token);
+
+        if quote.deposit > 0 {
+            events::deposit::deposit_locked(env, renter, car_id, rental.rental_id, quote.deposit);
+        }
         Ok(())
     }
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error> {
-        owner.require_auth();
+    fn reserve(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        start_ts: u64,
+        end_ts: u64,
+        amount: i128,
+        token: Address,
+    ) -> Result<u32, Error> {
+        renter.require_auth();
+        ensure_not_paused(env)?;
 
         if amount <= 0 {
             return Err(Error::AmountMustBePositive);
         }
 
-        if !has_car(env, &owner) {
+        if start_ts < env.ledger().timestamp() || end_ts <= start_ts {
+            return Err(Error::InvalidBookingWindow);
+        }
+
+        if !has_car(env, car_id) {
             return Err(Error::CarNotFound);
         }
 
-        let mut car = read_car(&env, &owner)?;
+        let car = read_car(env, car_id)?;
 
-        // Owner can only withdraw when car is returned (Available)
-        if car.car_status != CarStatus::Available {
-            return Err(Error::CarAlreadyRented);
+        if renter == car.owner {
+            return Err(Error::SelfRentalNotAllowed);
         }
 
-        if amount > car.available_to_withdraw {
-            return Err(Error::InsufficientBalance);
+        if car.car_status == CarStatus::Delisted {
+            return Err(Error::CarDelisted);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        ensure_token_accepted(env, car_id, &token)?;
 
-        if amount > contract_balance {
-            return Err(Error::BalanceNotAvailableForAmountRequested);
+        // Partial days are charged as full days
+        let total_days_to_rent: u32 = (end_ts - start_ts)
+            .div_ceil(SECONDS_PER_DAY)
+            .try_into()
+            .map_err(|_| Error::OverflowError)?;
+
+        ensure_window_is_free(env, car_id, start_ts, end_ts)?;
+
+        if has_rental(env, car_id) && read_rental(env, car_id)?.due_ts > start_ts {
+            return Err(Error::BookingOverlap);
         }
 
-        car.available_to_withdraw = car
-            .available_to_withdraw
-            .checked_sub(amount)
+        // Reservations whose window is over no longer hold a slot, even before they are expired
+        let upcoming = bookings_in_window(env, car_id, env.ledger().timestamp(), u64::MAX)?;
+
+        if upcoming.len() >= MAX_BOOKINGS_PER_CAR {
+            return Err(Error::TooManyBookings);
+        }
+
+        // Keeps one renter from holding the whole calendar with reservations it can cancel for free
+        let renter_bookings = upcoming.iter().filter(|booking| booking.renter == renter).count();
+
+        if renter_bookings >= MAX_BOOKINGS_PER_RENTER as usize {
+            return Err(Error::TooManyBookings);
+        }
+
+        let mut booking_ids = read_car_bookings(env, car_id);
+
+        let quote = compute_rental_quote(env, car_id, &car, &token, total_days_to_rent)?;
+
+        // Reservations are paid in full when booked; the commission is only earned once the rental starts
+        let total_payment = quote.total
+            .checked_add(quote.deposit)
             .ok_or(Error::OverflowError)?;
-        contract_balance = contract_balance
-            .checked_sub(amount)
+
+        if amount < total_payment {
+            return Err(Error::InsufficientPayment);
+        }
+
+        let booking_id = read_next_booking_id(env);
+        let next_booking_id = booking_id.checked_add(1).ok_or(Error::OverflowError)?;
+
+        let contract_balance = read_contract_balance(env, &token)
+            .checked_add(quote.total)
             .ok_or(Error::OverflowError)?;
 
-        write_car(&env, &owner, &car);
-        write_contract_balance(&env, &contract_balance);
+        let deposit_balance = read_deposit_balance(env, &token)
+            .checked_add(quote.deposit)
+            .ok_or(Error::OverflowError)?;
 
-        token_transfer(&env, &env.current_contract_address(), &owner, &amount)?;
-        events::payout_owner::payout_owner(env, owner, amount);
-        Ok(())
+        let booking = Booking {
+            booking_id,
+            car_id,
+            renter: renter.clone(),
+            start_ts,
+            end_ts,
+            total_days_to_rent,
+            amount: quote.base_price,
+            commission: quote.commission,
+            deposit: quote.deposit,
+            status: BookingStatus::Reserved,
+        };
+
+        booking_ids.push_back(booking_id);
+
+        write_contract_balance(env, &token, &contract_balance);
+        write_deposit_balance(env, &token, &deposit_balance);
+        write_booking(env, booking_id, &booking);
+        write_booking_token(env, booking_id, &token);
+        write_car_bookings(env, car_id, &booking_ids);
+        write_next_booking_id(env, next_booking_id);
+
+        token_transfer(env, &token, &renter, &env.current_contract_address(), &total_payment)?;
+        events::booking::car_reserved(env, renter, car_id, booking_id, start_ts, end_ts, quote.base_price);
+        Ok(booking_id)
+    }
+
+    fn start_reservation(env: &Env, booking_id: u32) -> Result<u32, Error> {
+        ensure_not_paused(env)?;
+
+        let mut booking = read_booking(env, booking_id)?;
+
+        if booking.status != BookingStatus::Reserved {
+            return Err(Error::InvalidBookingStatus);
+        }
+
+        let now = env.ledger().timestamp();
+
+        if now < booking.start_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        if now >= booking.end_ts {
+            return Err(Error::BookingExpired);
+        }
+
+        let car_id = booking.car_id;
+        let mut car = read_car(env, car_id)?;
+
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarUnderMaintenance);
+        }
+
+        if car.car_status != CarStatus::Available {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        // Payment was settled at booking time, so only the rental records are created here
+        let rental = Rental {
+            rental_id: allocate_rental_id(env)?,
+            renter: booking.renter.clone(),
+            total_days_to_rent: booking.total_days_to_rent,
+            amount: booking.amount,
+            deposit: booking.deposit,
+            start_ts: booking.start_ts,
+            due_ts: booking.end_ts,
+        };
+
+        // The rental keeps the token the reservation was paid with
+        let token = read_booking_token(env, booking_id)?;
+
+        write_rental_token(env, rental.rental_id, &token);
+        open_rental(env, car_id, &mut car, &rental, booking.commission)?;
+        credit_commission(env, &car, &token, booking.commission)?;
+        remove_car_booking(env, car_id, booking_id);
+
+        booking.status = BookingStatus::Active;
+
+        write_booking(env, booking_id, &booking);
+        events::booking::reservation_started(env, booking.renter.clone(), car_id, booking_id, rental.rental_id);
+
+        if booking.deposit > 0 {
+            events::deposit::deposit_locked(env, booking.renter, car_id, rental.rental_id, booking.deposit);
+        }
+        Ok(rental.rental_id)
+    }
+
+    fn cancel_rental(env: &Env, renter: Address, booking_id: u32) -> Result<i128, Error> {
+        renter.require_auth();
+
+        let mut booking = read_booking(env, booking_id)?;
+
+        if booking.renter != renter {
+            return Err(Error::Unauthorized);
+        }
+
+        // Started rentals end through return_car instead
+        if booking.status != BookingStatus::Reserved {
+            return Err(Error::InvalidBookingStatus);
+        }
+
+        let refund_bps = cancellation_refund_bps(env, &booking, env.ledger().timestamp())?;
+        let refund = settle_unstarted_booking(env, &mut booking, refund_bps, BookingStatus::Cancelled)?;
+
+        events::cancel_rental::booking_cancelled(env, renter, booking.car_id, booking_id, refund, booking.deposit);
+        Ok(refund)
+    }
+
+    fn expire_booking(env: &Env, booking_id: u32) -> Result<i128, Error> {
+        let mut booking = read_booking(env, booking_id)?;
+
+        if booking.status != BookingStatus::Reserved {
+            return Err(Error::InvalidBookingStatus);
+        }
+
+        let now = env.ledger().timestamp();
+
+        // Until its window is over the renter can still start or cancel the reservation
+        if now < booking.end_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        let refund_bps = cancellation_refund_bps(env, &booking, now)?;
+        let refund = settle_unstarted_booking(env, &mut booking, refund_bps, BookingStatus::Expired)?;
+
+        events::booking::booking_expired(env, booking.renter.clone(), booking.car_id, booking_id, refund, booking.deposit);
+        Ok(refund)
     }
 
-    fn set_admin_commission(env: &Env, commission: i128) -> Result<(), Error> {
+    fn set_cancellation_policy(env: &Env, full_refund_window: u64, partial_refund_bps: u32) -> Result<(), Error> {
         let admin = read_admin(env)?;
         admin.require_auth();
 
-        if commission < 0 {
-            return Err(Error::AmountMustBePositive);
+        if partial_refund_bps as i128 > BASIS_POINTS_DENOMINATOR {
+            return Err(Error::InvalidRefundPolicy);
         }
 
-        write_admin_commission(env, commission);
+        let policy = CancellationPolicy {
+            full_refund_window,
+            partial_refund_bps,
+        };
+
+        write_cancellation_policy(env, &policy);
         Ok(())
     }
 
-    fn withdraw_admin_commission(env: &Env, amount: i128) -> Result<(), Error> {
-        let admin = read_admin(env)?;
-        admin.require_auth();
+    fn get_cancellation_policy(env: &Env) -> CancellationPolicy {
+        read_cancellation_policy(env)
+    }
 
-        if amount <= 0 {
-            return Err(Error::AmountMustBePositive);
+    fn get_booking(env: &Env, booking_id: u32) -> Result<Booking, Error> {
+        public::get_bookings::get_booking(env, booking_id)
+    }
+
+    fn get_booking_token(env: &Env, booking_id: u32) -> Result<Address, Error> {
+        public::get_bookings::get_booking_token(env, booking_id)
+    }
+
+    fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error> {
+        public::get_bookings::get_bookings(env, car_id, from, to)
+    }
+
+    fn remove_car(env: &Env, caller: Address, car_id: u32) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::FleetManager)?;
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
         }
 
-        let mut admin_available = read_admin_available_to_withdraw(env);
+        let mut car = read_car(env, car_id)?;
 
-        if amount > admin_available {
-            return Err(Error::InsufficientBalance);
+        // A rented car still backs an active rental and its pending earnings
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
         }
 
-        let mut contract_balance = read_contract_balance(&env);
+        if car.car_status == CarStatus::Delisted {
+            return Err(Error::CarDelisted);
+        }
 
-        if amount > contract_balance {
-            return Err(Error::BalanceNotAvailableForAmountRequested);
+        // Relisting sets the car available, so it has to leave maintenance through end_maintenance first
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarUnderMaintenance);
         }
 
-        admin_available = admin_available
-            .checked_sub(amount)
-            .ok_or(Error::OverflowError)?;
-        contract_balance = contract_balance
-            .checked_sub(amount)
-            .ok_or(Error::OverflowError)?;
+        // Paid reservations must be started, cancelled or expired first
+        if !read_car_bookings(env, car_id).is_empty() {
+            return Err(Error::CarHasBookings);
+        }
 
-        write_admin_available_to_withdraw(env, admin_available);
-        write_contract_balance(&env, &contract_balance);
+        // The record is kept so its history and the owner's balance stay reachable
+        car.car_status = CarStatus::Delisted;
 
-        token_transfer(&env, &env.current_contract_address(), &admin, &amount)?;
+        write_car(env, car_id, &car);
+        events::remove_car::car_removed(env, car_id);
         Ok(())
     }
 
-    fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error> {
-        renter.require_auth();
+    fn bump_car(env: &Env, car_id: u32) -> Result<(), Error> {
+        methods::storage::bump::bump_car(env, car_id)
+    }
 
-        if renter == owner {
-            return Err(Error::SelfRentalNotAllowed);
-        }
+    fn migrate_legacy_cars(env: &Env, cars: Vec<LegacyCarRef>) -> Result<u32, Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
 
-        if !has_car(env, &owner) {
-            return Err(Error::CarNotFound);
-        }
+        methods::storage::migrate::migrate_legacy_cars(env, cars)
+    }
 
-        if !has_rental(env, &renter, &owner) {
-            return Err(Error::RentalNotFound);
+    fn relist_car(env: &Env, caller: Address, car_id: u32) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::FleetManager)?;
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
         }
 
-        let mut car = read_car(env, &owner)?;
+        let mut car = read_car(env, car_id)?;
 
-        if car.car_status != CarStatus::Rented {
-            return Err(Error::RentalNotFound);
+        if car.car_status != CarStatus::Delisted {
+            return Err(Error::CarNotDelisted);
         }
 
         car.car_status = CarStatus::Available;
 
-        write_car(env, &owner, &car);
-        remove_rental(env, &renter, &owner);
+        write_car(env, car_id, &car);
+        events::relist_car::car_relisted(env, car_id);
+        Ok(())
+    }
+    fn payout_owner(env: &Env, owner: Address, amount: i128, token: Address) -> Result<(), Error> {
+        owner.require_auth();
+        ensure_not_paused(env)?;
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // Earnings are pooled per token across all of the owner's cars and only credited on return
+        let mut owner_available = read_owner_available_to_withdraw(env, &owner, &token);
+
+        if amount > owner_available {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let mut contract_balance = read_contract_balance(env, &token);
+
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        owner_available = owner_available
+            .checked_sub(amount)
+            .ok_or(Error::OverflowError)?;
+        contract_balance = contract_balance
+            .checked_sub(amount)
+            .ok_or(Error::OverflowError)?;
+
+        write_owner_available_to_withdraw(env, &owner, &token, owner_available);
+        write_contract_balance(env, &token, &contract_balance);
+
+        token_transfer(env, &token, &env.current_contract_address(), &owner, &amount)?;
+        events::payout_owner::payout_owner(env, owner, token, amount);
+        Ok(())
+    }
+
+    fn set_admin_commission(env: &Env, caller: Address, commission: i128) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Finance)?;
+
+        let config = CommissionConfig {
+            mode: CommissionMode::Flat,
+            flat_fee: commission,
+            bps: 0,
+            min_fee: 0,
+            max_fee: 0,
+        };
+
+        validate_commission(&config)?;
+        write_admin_commission(env, &config);
+        Ok(())
+    }
+
+    fn set_admin_commission_bps(env: &Env, caller: Address, bps: u32, min_fee: i128, max_fee: i128) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Finance)?;
+
+        let config = CommissionConfig {
+            mode: CommissionMode::BasisPoints,
+            flat_fee: 0,
+            bps,
+            min_fee,
+            max_fee,
+        };
+
+        validate_commission(&config)?;
+        write_admin_commission(env, &config);
+        Ok(())
+    }
+
+    fn get_admin_commission(env: &Env) -> CommissionConfig {
+        public::get_admin_commission::get_admin_commission(env)
+    }
+
+    fn set_token_commission(env: &Env, caller: Address, token: Address, config: CommissionConfig) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Finance)?;
+
+        // The default token is charged through set_admin_commission and set_admin_commission_bps
+        if token == read_token(env)? || !is_token_allowed(env, &token)? {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        validate_commission(&config)?;
+        write_token_commission(env, &token, &config);
+        Ok(())
+    }
+
+    fn get_token_commission(env: &Env, token: Address) -> Result<CommissionConfig, Error> {
+        commission_config_in(env, &token)
+    }
+
+    fn withdraw_admin_commission(env: &Env, caller: Address, amount: i128, token: Address) -> Result<(), Error> {
+        require_admin_or_role(env, &caller, Role::Finance)?;
+        ensure_not_paused(env)?;
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut admin_available = read_admin_available_to_withdraw(env, &token);
+
+        if amount > admin_available {
+            return Err(Error::InsufficientBalance);
+        }
+
+        let mut contract_balance = read_contract_balance(env, &token);
+
+        if amount > contract_balance {
+            return Err(Error::BalanceNotAvailableForAmountRequested);
+        }
+
+        admin_available = admin_available
+            .checked_sub(amount)
+            .ok_or(Error::OverflowError)?;
+        contract_balance = contract_balance
+            .checked_sub(amount)
+            .ok_or(Error::OverflowError)?;
+
+        write_admin_available_to_withdraw(env, &token, admin_available);
+        write_contract_balance(env, &token, &contract_balance);
+
+        // Finance accounts may trigger the withdrawal, but the commission only ever goes to the treasury
+        let treasury = read_treasury(env)?;
+
+        token_transfer(env, &token, &env.current_contract_address(), &treasury, &amount)?;
+        Ok(())
+    }
+
+    fn set_treasury(env: &Env, treasury: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_treasury(env, &treasury);
+        events::admin::treasury_set(env, treasury);
+        Ok(())
+    }
+
+    fn get_treasury(env: &Env) -> Result<Address, Error> {
+        read_treasury(env)
+    }
+
+    fn extend_rental(env: &Env, renter: Address, car_id: u32, extra_days: u32) -> Result<(), Error> {
+        renter.require_auth();
+        ensure_not_paused(env)?;
+
+        if extra_days == 0 {
+            return Err(Error::RentalDurationCannotBeZero);
+        }
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut rental = read_rental(env, car_id)?;
+
+        if rental.renter != renter {
+            return Err(Error::RentalNotFound);
+        }
+
+        // Overdue rentals have to be returned rather than extended
+        if env.ledger().timestamp() > rental.due_ts {
+            return Err(Error::RentalOverdue);
+        }
+
+        let car = read_car(env, car_id)?;
+
+        // A car already handed back cannot be kept any longer
+        if car.car_status == CarStatus::AwaitingReturnConfirmation {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let extra_duration = (extra_days as u64)
+            .checked_mul(SECONDS_PER_DAY)
+            .ok_or(Error::OverflowError)?;
+        let due_ts = rental.due_ts
+            .checked_add(extra_duration)
+            .ok_or(Error::OverflowError)?;
+
+        ensure_window_is_free(env, car_id, rental.due_ts, due_ts)?;
+
+        // Extensions are paid in the rental's token, as long as the admin still allows it
+        let token = read_rental_token(env, rental.rental_id)?;
+
+        if !is_token_allowed(env, &token)? {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        let quote = compute_rental_quote(env, car_id, &car, &token, extra_days)?;
+
+        let contract_balance = read_contract_balance(env, &token)
+            .checked_add(quote.total)
+            .ok_or(Error::OverflowError)?;
+
+        // The deposit already locked for the rental keeps covering the extension
+        rental.total_days_to_rent = rental.total_days_to_rent
+            .checked_add(extra_days)
+            .ok_or(Error::OverflowError)?;
+        rental.amount = rental.amount
+            .checked_add(quote.base_price)
+            .ok_or(Error::OverflowError)?;
+        rental.due_ts = due_ts;
+
+        write_contract_balance(env, &token, &contract_balance);
+        write_rental(env, car_id, &rental);
+        record_rental_extended(env, &rental, quote.commission)?;
+        credit_commission(env, &car, &token, quote.commission)?;
+
+        token_transfer(env, &token, &renter, &env.current_contract_address(), &quote.total)?;
+        events::rental::extended(env, renter, car_id, extra_days, quote.base_price, due_ts);
+        Ok(())
+    }
+
+    fn return_car(env: &Env, renter: Address, car_id: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if renter == car.owner {
+            return Err(Error::SelfRentalNotAllowed);
+        }
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let rental = read_rental(env, car_id)?;
+
+        if rental.renter != renter {
+            return Err(Error::RentalNotFound);
+        }
+
+        if car.car_status == CarStatus::AwaitingPickup {
+            return Err(Error::CarNotHandedOver);
+        }
+
+        if car.car_status != CarStatus::Rented {
+            return Err(Error::RentalNotFound);
+        }
+
+        let returned_ts = env.ledger().timestamp();
+
+        // With a handoff the owner has to confirm the car is back before any money moves,
+        // but late fees are still measured from the renter's return
+        if has_handoff(env, rental.rental_id) {
+            let mut handoff = read_handoff(env, rental.rental_id)?;
+            handoff.returned_ts = returned_ts;
+            car.car_status = CarStatus::AwaitingReturnConfirmation;
+
+            write_car(env, car_id, &car);
+            write_handoff(env, rental.rental_id, &handoff);
+            events::handoff::return_pending(env, renter, car_id, rental.rental_id);
+            return charge_late_fee(env, car_id, &rental, &car.owner, returned_ts, true);
+        }
+
+        let (timing, refund) = close_rental(env, car_id, &mut car, &rental, returned_ts, RentalOutcome::Returned)?;
+
+        events::rental::car_returned(env, renter, car_id, timing, refund);
+        charge_late_fee(env, car_id, &rental, &car.owner, returned_ts, true)?;
+        hand_back_deposit(env, rental.rental_id)
+    }
+
+    fn force_return(env: &Env, caller: Address, car_id: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        // Only a car the renter still has can be reclaimed
+        if car.car_status != CarStatus::Rented {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+        let returned_ts = env.ledger().timestamp();
+
+        let reclaimable_ts = rental.due_ts
+            .checked_add(read_reclaim_grace_period(env))
+            .ok_or(Error::OverflowError)?;
+
+        if returned_ts <= reclaimable_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        close_rental(env, car_id, &mut car, &rental, returned_ts, RentalOutcome::Reclaimed)?;
+
+        events::rental::car_reclaimed(env, caller, car_id, rental.renter.clone(), rental.rental_id, rental.due_ts);
+
+        // The renter is not signing, so penalties can only come out of the held deposit
+        charge_late_fee(env, car_id, &rental, &car.owner, returned_ts, false)?;
+        hand_back_deposit(env, rental.rental_id)
+    }
+
+    fn set_handoff_required(env: &Env, caller: Address, car_id: u32, required: bool) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        // The active rental keeps the terms it was taken under
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        write_handoff_required(env, car_id, required);
+        Ok(())
+    }
+
+    fn get_handoff_required(env: &Env, car_id: u32) -> Result<bool, Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        Ok(read_handoff_required(env, car_id))
+    }
+
+    fn mark_handed_over(env: &Env, caller: Address, car_id: u32, odometer: u64, fuel_level: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if fuel_level > MAX_FUEL_LEVEL {
+            return Err(Error::InvalidReading);
+        }
+
+        if car.car_status != CarStatus::AwaitingPickup {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+        let mut handoff = read_handoff(env, rental.rental_id)?;
+
+        if handoff.handed_over {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        handoff.handed_over = true;
+        handoff.handed_over_ts = env.ledger().timestamp();
+        handoff.pickup_odometer = odometer;
+        handoff.pickup_fuel_level = fuel_level;
+
+        write_handoff(env, rental.rental_id, &handoff);
+        events::handoff::car_handed_over(env, caller, car_id, rental.rental_id, odometer, fuel_level);
+        Ok(())
+    }
+
+    fn confirm_pickup(env: &Env, renter: Address, car_id: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let rental = read_rental(env, car_id)?;
+
+        if rental.renter != renter {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if car.car_status != CarStatus::AwaitingPickup {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        if !read_handoff(env, rental.rental_id)?.handed_over {
+            return Err(Error::CarNotHandedOver);
+        }
+
+        complete_pickup(env, car_id, &mut car, &rental)?;
+        events::handoff::pickup_confirmed(env, renter, car_id, rental.rental_id);
+        Ok(())
+    }
+
+    fn finalize_pickup(env: &Env, car_id: u32) -> Result<(), Error> {
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if car.car_status != CarStatus::AwaitingPickup {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+        let handoff = read_handoff(env, rental.rental_id)?;
+
+        if !handoff.handed_over {
+            return Err(Error::CarNotHandedOver);
+        }
+
+        let finalizable_ts = handoff.handed_over_ts
+            .checked_add(PICKUP_TIMEOUT)
+            .ok_or(Error::OverflowError)?;
+
+        // A renter who drives off without confirming cannot keep the rental from starting
+        if env.ledger().timestamp() < finalizable_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        complete_pickup(env, car_id, &mut car, &rental)?;
+        events::handoff::pickup_finalized(env, car_id, rental.rental_id);
+        Ok(())
+    }
+
+    fn cancel_pickup(env: &Env, car_id: u32) -> Result<i128, Error> {
+        if !has_rental(env, car_id) {
+            return Err(Error::RentalNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if car.car_status != CarStatus::AwaitingPickup {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+
+        // Once the car is handed over the renter has it, so the rental can only be finalized
+        if read_handoff(env, rental.rental_id)?.handed_over {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let now = env.ledger().timestamp();
+
+        let deadline = rental.start_ts
+            .checked_add(PICKUP_TIMEOUT)
+            .ok_or(Error::OverflowError)?;
+
+        if now < deadline {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        // The renter never had the car, so the price and the commission held back go back in full
+        let commission = read_rental_record(env, rental.rental_id)?.commission;
+        let refund = rental.amount
+            .checked_add(commission)
+            .ok_or(Error::OverflowError)?;
+
+        let token = read_rental_token(env, rental.rental_id)?;
+        let contract_balance = read_contract_balance(env, &token)
+            .checked_sub(refund)
+            .ok_or(Error::OverflowError)?;
+
+        car.car_status = CarStatus::Available;
+
+        write_contract_balance(env, &token, &contract_balance);
+        write_car(env, car_id, &car);
+        remove_rental(env, car_id);
+        record_rental_closed(env, rental.rental_id, now, refund, RentalOutcome::Cancelled)?;
+
+        token_transfer(env, &token, &env.current_contract_address(), &rental.renter, &refund)?;
+        events::handoff::pickup_cancelled(env, rental.renter.clone(), car_id, rental.rental_id, refund);
+
+        if has_deposit(env, rental.rental_id) {
+            let mut deposit = read_deposit(env, rental.rental_id)?;
+            release_deposit_to_renter(env, rental.rental_id, &mut deposit)?;
+        }
+        Ok(refund)
+    }
+
+    fn confirm_return(env: &Env, caller: Address, car_id: u32, odometer: u64, fuel_level: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if fuel_level > MAX_FUEL_LEVEL {
+            return Err(Error::InvalidReading);
+        }
+
+        if car.car_status != CarStatus::AwaitingReturnConfirmation {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+        let mut handoff = read_handoff(env, rental.rental_id)?;
+
+        // The odometer never runs backwards while the renter has the car
+        if odometer < handoff.pickup_odometer {
+            return Err(Error::InvalidReading);
+        }
+
+        handoff.return_odometer = Some(odometer);
+        handoff.return_fuel_level = Some(fuel_level);
+
+        write_handoff(env, rental.rental_id, &handoff);
+        events::handoff::return_confirmed(env, caller, car_id, rental.rental_id, odometer, fuel_level);
+        settle_handoff_return(env, car_id, &mut car, &rental, &handoff)
+    }
+
+    fn finalize_return(env: &Env, car_id: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+
+        if car.car_status != CarStatus::AwaitingReturnConfirmation {
+            return Err(Error::InvalidHandoffStatus);
+        }
+
+        let rental = read_rental(env, car_id)?;
+        let handoff = read_handoff(env, rental.rental_id)?;
+
+        let finalizable_ts = handoff.returned_ts
+            .checked_add(RETURN_CONFIRMATION_TIMEOUT)
+            .ok_or(Error::OverflowError)?;
+
+        // An owner who never confirms cannot hold the renter's refund and deposit forever
+        if env.ledger().timestamp() < finalizable_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        events::handoff::return_finalized(env, car_id, rental.rental_id);
+        settle_handoff_return(env, car_id, &mut car, &rental, &handoff)
+    }
+
+    fn get_handoff(env: &Env, rental_id: u32) -> Result<Handoff, Error> {
+        read_handoff(env, rental_id)
+    }
+
+    fn set_reclaim_grace_period(env: &Env, seconds: u64) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_reclaim_grace_period(env, seconds);
+        Ok(())
+    }
+
+    fn get_reclaim_grace_period(env: &Env) -> u64 {
+        read_reclaim_grace_period(env)
+    }
+
+    fn update_car(env: &Env, caller: Address, car_id: u32, price_per_day: i128) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if price_per_day <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // The active rental's price is locked until the car is returned
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        let old_price_per_day = car.price_per_day;
+        car.price_per_day = price_per_day;
+
+        write_car(env, car_id, &car);
+        events::update_car::car_updated(env, caller, car_id, old_price_per_day, price_per_day);
+        Ok(())
+    }
+
+    fn update_car_metadata(env: &Env, caller: Address, car_id: u32, metadata: CarMetadata) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        validate_car_metadata(&metadata)?;
+
+        write_car_metadata(env, car_id, &metadata);
+        events::update_car::car_metadata_updated(env, caller, car_id, metadata);
+        Ok(())
+    }
+
+    fn set_car_deposit(env: &Env, caller: Address, car_id: u32, deposit_amount: i128) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if deposit_amount < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // Active rentals keep the deposit they were charged
+        let old_deposit_amount = car.deposit_amount;
+        car.deposit_amount = deposit_amount;
+
+        write_car(env, car_id, &car);
+        events::deposit::deposit_set(env, caller, car_id, old_deposit_amount, deposit_amount);
+        Ok(())
+    }
+
+    fn set_car_tokens(env: &Env, caller: Address, car_id: u32, tokens: Vec<Address>) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if tokens.is_empty() {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        for token in tokens.iter() {
+            if !is_token_allowed(env, &token)? {
+                return Err(Error::TokenNotAllowed);
+            }
+
+            // Without a price in the token the car's default-token amounts would be charged in it
+            car_price_in(env, car_id, &car, &token)?;
+        }
+
+        // Active rentals and reservations keep the token they were paid with
+        write_car_tokens(env, car_id, &tokens);
+        events::update_car::car_tokens_updated(env, caller, car_id, tokens);
+        Ok(())
+    }
+
+    fn get_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        read_car_tokens(env, car_id)
+    }
+
+    fn set_car_token_price(env: &Env, caller: Address, car_id: u32, token: Address, price: CarTokenPrice) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        // The default token is priced by the car itself, through update_car, set_car_deposit and its late-fee policy
+        if token == read_token(env)? || !is_token_allowed(env, &token)? {
+            return Err(Error::TokenNotAllowed);
+        }
+
+        if price.price_per_day <= 0 || price.deposit_amount < 0 || price.late_fee < 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        // Like the late-fee policy, the late fee cannot change under an active rental
+        let late_fee_changed = read_car_token_price(env, car_id, &token)
+            .is_some_and(|current| current.late_fee != price.late_fee);
+
+        if late_fee_changed && (car.car_status == CarStatus::Rented || has_rental(env, car_id)) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        write_car_token_price(env, car_id, &token, &price);
+        events::update_car::car_token_price_set(env, caller, car_id, token, price);
+        Ok(())
+    }
+
+    fn get_car_token_price(env: &Env, car_id: u32, token: Address) -> Result<CarTokenPrice, Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+
+        car_price_in(env, car_id, &car, &token)
+    }
+
+    fn set_early_return_policy(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        policy: Option<EarlyReturnPolicy>,
+    ) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        // The active rental keeps the terms it was taken under
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        match policy {
+            Some(policy) => write_early_return_policy(env, car_id, &policy),
+            None => remove_early_return_policy(env, car_id),
+        }
+        Ok(())
+    }
+
+    fn get_early_return_policy(env: &Env, car_id: u32) -> Result<Option<EarlyReturnPolicy>, Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        Ok(read_early_return_policy(env, car_id))
+    }
+
+    fn set_late_fee_policy(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        policy: Option<LateFeePolicy>,
+    ) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        // The active rental keeps the terms it was taken under
+        if car.car_status == CarStatus::Rented || has_rental(env, car_id) {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        match policy {
+            Some(policy) => {
+                if policy.fee < 0 {
+                    return Err(Error::AmountMustBePositive);
+                }
+
+                write_late_fee_policy(env, car_id, &policy);
+            }
+            None => remove_late_fee_policy(env, car_id),
+        }
+        Ok(())
+    }
+
+    fn get_late_fee_policy(env: &Env, car_id: u32) -> Result<Option<LateFeePolicy>, Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        Ok(read_late_fee_policy(env, car_id))
+    }
+
+    fn set_late_fee_platform_share(env: &Env, bps: u32) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        if bps as i128 > BASIS_POINTS_DENOMINATOR {
+            return Err(Error::InvalidCommission);
+        }
+
+        write_late_fee_platform_share(env, bps);
+        Ok(())
+    }
+
+    fn get_late_fee_platform_share(env: &Env) -> u32 {
+        read_late_fee_platform_share(env)
+    }
+
+    fn set_inspection_window(env: &Env, seconds: u64) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        write_inspection_window(env, seconds);
+        Ok(())
+    }
+
+    fn release_deposit(env: &Env, rental_id: u32) -> Result<(), Error> {
+        let mut deposit = read_deposit(env, rental_id)?;
+
+        if deposit.status != DepositStatus::InInspection {
+            return Err(Error::DepositNotReleasable);
+        }
+
+        // Anyone may release once the owner's inspection window has passed without a claim
+        if env.ledger().timestamp() < deposit.release_ts {
+            return Err(Error::TimeoutNotReached);
+        }
+
+        release_deposit_to_renter(env, rental_id, &mut deposit)
+    }
+
+    fn get_deposit(env: &Env, rental_id: u32) -> Result<Deposit, Error> {
+        public::get_deposit::get_deposit(env, rental_id)
+    }
+
+    fn set_arbiter(env: &Env, arbiter: Address) -> Result<(), Error> {
+        let admin = read_admin(env)?;
+        admin.require_auth();
+
+        // Disputes are about the platform's and owners' money, so neither may decide them
+        if arbiter == admin || is_car_owner(env, &arbiter) {
+            return Err(Error::InvalidArbiter);
+        }
+
+        write_arbiter(env, &arbiter);
+        events::damage_claim::arbiter_set(env, arbiter);
+        Ok(())
+    }
+
+    fn get_arbiter(env: &Env) -> Result<Address, Error> {
+        read_arbiter(env)
+    }
+
+    fn file_damage_claim(
+        env: &Env,
+        owner: Address,
+        rental_id: u32,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), Error> {
+        owner.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::AmountMustBePositive);
+        }
+
+        let mut deposit = read_deposit(env, rental_id)?;
+        let car = read_car(env, deposit.car_id)?;
+
+        if owner != car.owner {
+            return Err(Error::Unauthorized);
+        }
+
+        if has_damage_claim(env, rental_id) {
+            return Err(Error::DamageClaimAlreadyFiled);
+        }
+
+        if deposit.status != DepositStatus::InInspection {
+            return Err(Error::DepositNotReleasable);
+        }
+
+        if env.ledger().timestamp() >= deposit.release_ts {
+            return Err(Error::InspectionWindowClosed);
+        }
+
+        if amount > deposit.amount {
+            return Err(Error::ClaimExceedsDeposit);
+        }
+
+        // The deposit stays frozen until the renter accepts or the arbiter rules
+        deposit.status = DepositStatus::Claimed;
+
+        let claim = DamageClaim {
+            car_id: deposit.car_id,
+            owner: owner.clone(),
+            renter: deposit.renter.clone(),
+            amount,
+            evidence_hash: evidence_hash.clone(),
+            status: ClaimStatus::Filed,
+            owner_award: 0,
+        };
+
+        write_deposit(env, rental_id, &deposit);
+        write_damage_claim(env, rental_id, &claim);
+
+        events::damage_claim::damage_claim_filed(env, owner, rental_id, amount, evidence_hash);
+        Ok(())
+    }
+
+    fn accept_damage_claim(env: &Env, renter: Address, rental_id: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        let mut claim = read_damage_claim(env, rental_id)?;
+
+        if renter != claim.renter {
+            return Err(Error::Unauthorized);
+        }
+
+        if claim.status != ClaimStatus::Filed {
+            return Err(Error::InvalidClaimStatus);
+        }
+
+        let mut deposit = read_deposit(env, rental_id)?;
+        let renter_amount = settle_deposit(env, rental_id, &mut deposit, &claim.owner, claim.amount)?;
+
+        claim.status = ClaimStatus::Accepted;
+        claim.owner_award = claim.amount;
+        write_damage_claim(env, rental_id, &claim);
+
+        events::damage_claim::damage_claim_accepted(env, renter, rental_id, claim.amount, renter_amount);
+        Ok(())
+    }
+
+    fn contest_damage_claim(env: &Env, renter: Address, rental_id: u32) -> Result<(), Error> {
+        renter.require_auth();
+
+        let mut claim = read_damage_claim(env, rental_id)?;
+
+        if renter != claim.renter {
+            return Err(Error::Unauthorized);
+        }
+
+        if claim.status != ClaimStatus::Filed {
+            return Err(Error::InvalidClaimStatus);
+        }
+
+        claim.status = ClaimStatus::Contested;
+        write_damage_claim(env, rental_id, &claim);
+
+        events::damage_claim::damage_claim_contested(env, renter, rental_id);
+        Ok(())
+    }
+
+    fn resolve_dispute(env: &Env, rental_id: u32, owner_amount: i128) -> Result<(), Error> {
+        let arbiter = read_arbiter(env)?;
+        arbiter.require_auth();
+
+        let mut claim = read_damage_claim(env, rental_id)?;
+
+        // Unanswered claims can also be ruled on so a silent renter cannot stall them
+        if claim.status != ClaimStatus::Filed && claim.status != ClaimStatus::Contested {
+            return Err(Error::InvalidClaimStatus);
+        }
+
+        let mut deposit = read_deposit(env, rental_id)?;
+        let renter_amount = settle_deposit(env, rental_id, &mut deposit, &claim.owner, owner_amount)?;
+
+        claim.status = ClaimStatus::Resolved;
+        claim.owner_award = owner_amount;
+        write_damage_claim(env, rental_id, &claim);
+
+        events::damage_claim::dispute_resolved(env, arbiter, rental_id, owner_amount, renter_amount);
+        Ok(())
+    }
+
+    fn get_damage_claim(env: &Env, rental_id: u32) -> Result<DamageClaim, Error> {
+        public::get_damage_claim::get_damage_claim(env, rental_id)
+    }
+
+    fn start_maintenance(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        reason_code: u32,
+        document_hash: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if car.car_status == CarStatus::Maintenance {
+            return Err(Error::CarUnderMaintenance);
+        }
+
+        if car.car_status == CarStatus::Delisted {
+            return Err(Error::CarDelisted);
+        }
+
+        if car.car_status != CarStatus::Available {
+            return Err(Error::CarAlreadyRented);
+        }
+
+        // Maintenance has no end time, so it cannot start while a reservation is still to come
+        ensure_window_is_free(env, car_id, env.ledger().timestamp(), u64::MAX)?;
+
+        car.car_status = CarStatus::Maintenance;
+
+        write_car(env, car_id, &car);
+        events::maintenance::maintenance_started(env, caller, car_id, reason_code, document_hash);
+        Ok(())
+    }
+
+    fn end_maintenance(env: &Env, caller: Address, car_id: u32) -> Result<(), Error> {
+        if !has_car(env, car_id) {
+            return Err(Error::CarNotFound);
+        }
+
+        let mut car = read_car(env, car_id)?;
+        require_owner_or_admin(env, &caller, &car)?;
+
+        if car.car_status != CarStatus::Maintenance {
+            return Err(Error::CarNotUnderMaintenance);
+        }
+
+        car.car_status = CarStatus::Available;
 
-        events::rental::car_returned(env, renter, owner);
+        write_car(env, car_id, &car);
+        events::maintenance::maintenance_ended(env, caller, car_id);
         Ok(())
     }
 }
@@ -1,10 +1,12 @@
 ﻿use soroban_sdk::{Address, Env, Symbol};
 
-pub(crate) fn car_added(env: &Env, owner: Address, price_per_day: i128) {
+use crate::storage::structs::car_metadata::CarMetadata;
+
+pub(crate) fn car_added(env: &Env, owner: Address, car_id: u32, price_per_day: i128, metadata: CarMetadata) {
     let topics = (Symbol::new(env, "car_added"), owner.clone());
 
     env.events().publish(
         topics,
-        price_per_day
+        (car_id, price_per_day, metadata)
     );
-}
\ No newline at end of file
+}
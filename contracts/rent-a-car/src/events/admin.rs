@@ -0,0 +1,31 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn admin_proposed(env: &Env, admin: Address, new_admin: Address, expires_ts: u64) {
+    let topics = (Symbol::new(env, "admin_proposed"), admin.clone());
+
+    env.events().publish(
+        topics,
+        (new_admin, expires_ts)
+    );
+}
+
+pub(crate) fn admin_transferred(env: &Env, old_admin: Address, new_admin: Address) {
+    let topics = (Symbol::new(env, "admin_transferred"),);
+
+    env.events().publish(
+        topics,
+        (old_admin, new_admin)
+    );
+}
+
+pub(crate) fn treasury_set(env: &Env, treasury: Address) {
+    let topics = (Symbol::new(env, "treasury_set"),);
+
+    env.events().publish(topics, treasury);
+}
+
+pub(crate) fn admin_renounced(env: &Env, admin: Address) {
+    let topics = (Symbol::new(env, "admin_renounced"), admin.clone());
+
+    env.events().publish(topics, ());
+}
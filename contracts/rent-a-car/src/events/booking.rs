@@ -0,0 +1,49 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn car_reserved(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    start_ts: u64,
+    end_ts: u64,
+    amount: i128
+) {
+    let topics = (Symbol::new(env, "car_reserved"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (booking_id, start_ts, end_ts, amount)
+    );
+}
+
+pub(crate) fn reservation_started(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    rental_id: u32
+) {
+    let topics = (Symbol::new(env, "reservation_started"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (booking_id, rental_id)
+    );
+}
+
+pub(crate) fn booking_expired(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    refund: i128,
+    deposit: i128
+) {
+    let topics = (Symbol::new(env, "booking_expired"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (booking_id, refund, deposit)
+    );
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn booking_cancelled(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    booking_id: u32,
+    refund: i128,
+    deposit: i128
+) {
+    let topics = (Symbol::new(env, "booking_cancelled"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (booking_id, refund, deposit)
+    );
+}
@@ -1,4 +1,4 @@
-﻿use soroban_sdk::{Address, Env, Symbol};
+﻿use soroban_sdk::{Address, BytesN, Env, Symbol};
 
 pub(crate) fn contract_initialized(env: &Env, admin: Address, token: Address) {
     let topics = (Symbol::new(env, "contract_initialized"),);
@@ -7,4 +7,28 @@ pub(crate) fn contract_initialized(env: &Env, admin: Address, token: Address) {
         topics,
         (admin, token)
     );
-}
\ No newline at end of file
+}
+
+#[allow(deprecated)]
+pub(crate) fn contract_upgraded(env: &Env, admin: Address, new_wasm_hash: BytesN<32>) {
+    let topics = (Symbol::new(env, "contract_upgraded"), admin.clone());
+
+    env.events().publish(topics, new_wasm_hash);
+}
+
+#[allow(deprecated)]
+pub(crate) fn legacy_car_migrated(env: &Env, owner: Address, car_id: u32) {
+    let topics = (Symbol::new(env, "legacy_car_migrated"), owner.clone());
+
+    env.events().publish(topics, car_id);
+}
+
+#[allow(deprecated)]
+pub(crate) fn schema_migrated(env: &Env, from_version: u32, to_version: u32, converted: u32) {
+    let topics = (Symbol::new(env, "schema_migrated"),);
+
+    env.events().publish(
+        topics,
+        (from_version, to_version, converted)
+    );
+}
@@ -0,0 +1,58 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn arbiter_set(env: &Env, arbiter: Address) {
+    let topics = (Symbol::new(env, "arbiter_set"),);
+
+    env.events().publish(topics, arbiter);
+}
+
+pub(crate) fn damage_claim_filed(
+    env: &Env,
+    owner: Address,
+    rental_id: u32,
+    amount: i128,
+    evidence_hash: BytesN<32>
+) {
+    let topics = (Symbol::new(env, "damage_claim_filed"), owner.clone(), rental_id);
+
+    env.events().publish(
+        topics,
+        (amount, evidence_hash)
+    );
+}
+
+pub(crate) fn damage_claim_accepted(
+    env: &Env,
+    renter: Address,
+    rental_id: u32,
+    owner_amount: i128,
+    renter_amount: i128
+) {
+    let topics = (Symbol::new(env, "damage_claim_accepted"), renter.clone(), rental_id);
+
+    env.events().publish(
+        topics,
+        (owner_amount, renter_amount)
+    );
+}
+
+pub(crate) fn damage_claim_contested(env: &Env, renter: Address, rental_id: u32) {
+    let topics = (Symbol::new(env, "damage_claim_contested"), renter.clone(), rental_id);
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn dispute_resolved(
+    env: &Env,
+    arbiter: Address,
+    rental_id: u32,
+    owner_amount: i128,
+    renter_amount: i128
+) {
+    let topics = (Symbol::new(env, "dispute_resolved"), arbiter.clone(), rental_id);
+
+    env.events().publish(
+        topics,
+        (owner_amount, renter_amount)
+    );
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn deposit_locked(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    rental_id: u32,
+    amount: i128
+) {
+    let topics = (Symbol::new(env, "deposit_locked"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (rental_id, amount)
+    );
+}
+
+pub(crate) fn deposit_in_inspection(
+    env: &Env,
+    rental_id: u32,
+    release_ts: u64
+) {
+    let topics = (Symbol::new(env, "deposit_in_inspection"), rental_id);
+
+    env.events().publish(topics, release_ts);
+}
+
+pub(crate) fn deposit_released(
+    env: &Env,
+    renter: Address,
+    rental_id: u32,
+    amount: i128
+) {
+    let topics = (Symbol::new(env, "deposit_released"), renter.clone(), rental_id);
+
+    env.events().publish(topics, amount);
+}
+
+pub(crate) fn deposit_set(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    old_deposit_amount: i128,
+    new_deposit_amount: i128
+) {
+    let topics = (Symbol::new(env, "deposit_set"), car_id, caller.clone());
+
+    env.events().publish(
+        topics,
+        (old_deposit_amount, new_deposit_amount)
+    );
+}
@@ -0,0 +1,63 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn car_handed_over(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    rental_id: u32,
+    odometer: u64,
+    fuel_level: u32
+) {
+    let topics = (Symbol::new(env, "car_handed_over"), car_id, caller.clone());
+
+    env.events().publish(
+        topics,
+        (rental_id, odometer, fuel_level)
+    );
+}
+
+pub(crate) fn pickup_confirmed(env: &Env, renter: Address, car_id: u32, rental_id: u32) {
+    let topics = (Symbol::new(env, "pickup_confirmed"), renter.clone(), car_id);
+
+    env.events().publish(topics, rental_id);
+}
+
+pub(crate) fn return_pending(env: &Env, renter: Address, car_id: u32, rental_id: u32) {
+    let topics = (Symbol::new(env, "return_pending"), renter.clone(), car_id);
+
+    env.events().publish(topics, rental_id);
+}
+
+pub(crate) fn return_confirmed(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    rental_id: u32,
+    odometer: u64,
+    fuel_level: u32
+) {
+    let topics = (Symbol::new(env, "return_confirmed"), car_id, caller.clone());
+
+    env.events().publish(
+        topics,
+        (rental_id, odometer, fuel_level)
+    );
+}
+
+pub(crate) fn return_finalized(env: &Env, car_id: u32, rental_id: u32) {
+    let topics = (Symbol::new(env, "return_finalized"), car_id);
+
+    env.events().publish(topics, rental_id);
+}
+
+pub(crate) fn pickup_finalized(env: &Env, car_id: u32, rental_id: u32) {
+    let topics = (Symbol::new(env, "pickup_finalized"), car_id);
+
+    env.events().publish(topics, rental_id);
+}
+
+pub(crate) fn pickup_cancelled(env: &Env, renter: Address, car_id: u32, rental_id: u32, refund: i128) {
+    let topics = (Symbol::new(env, "pickup_cancelled"), renter.clone(), car_id);
+
+    env.events().publish(topics, (rental_id, refund));
+}
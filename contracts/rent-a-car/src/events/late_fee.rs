@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn late_fee(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    fee: i128,
+    from_deposit: i128,
+    platform_share: i128
+) {
+    let topics = (Symbol::new(env, "late_fee"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (fee, from_deposit, platform_share)
+    );
+}
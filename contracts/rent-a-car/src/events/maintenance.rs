@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, BytesN, Env, Symbol};
+
+pub(crate) fn maintenance_started(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    reason_code: u32,
+    document_hash: Option<BytesN<32>>
+) {
+    let topics = (Symbol::new(env, "maintenance_started"), car_id, caller.clone());
+
+    env.events().publish(
+        topics,
+        (reason_code, document_hash)
+    );
+}
+
+pub(crate) fn maintenance_ended(env: &Env, caller: Address, car_id: u32) {
+    let topics = (Symbol::new(env, "maintenance_ended"), car_id, caller.clone());
+
+    env.events().publish(topics, ());
+}
@@ -1,5 +1,32 @@
-﻿pub mod contract;
+﻿// Events use `Events::publish` like the first-release modules until they all move to `#[contractevent]`.
+#[allow(deprecated)]
+pub mod admin;
+pub mod contract;
 pub mod remove_car;
 pub mod payout_owner;
+#[allow(deprecated)]
+pub mod relist_car;
 pub mod rental;
-pub mod add_car;
\ No newline at end of file
+pub mod add_car;
+#[allow(deprecated)]
+pub mod damage_claim;
+#[allow(deprecated)]
+pub mod deposit;
+#[allow(deprecated)]
+pub mod maintenance;
+#[allow(deprecated)]
+pub mod update_car;
+#[allow(deprecated)]
+pub mod booking;
+#[allow(deprecated)]
+pub mod cancel_rental;
+#[allow(deprecated)]
+pub mod late_fee;
+#[allow(deprecated)]
+pub mod handoff;
+#[allow(deprecated)]
+pub mod role;
+#[allow(deprecated)]
+pub mod pause;
+#[allow(deprecated)]
+pub mod token;
\ No newline at end of file
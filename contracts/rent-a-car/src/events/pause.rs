@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn paused(env: &Env, caller: Address) {
+    let topics = (Symbol::new(env, "paused"), caller.clone());
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn unpaused(env: &Env, caller: Address) {
+    let topics = (Symbol::new(env, "unpaused"), caller.clone());
+
+    env.events().publish(topics, ());
+}
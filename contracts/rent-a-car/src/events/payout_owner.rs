@@ -1,7 +1,7 @@
 ﻿use soroban_sdk::{Address, Env, Symbol};
 
-pub(crate) fn payout_owner(env: &Env, owner: Address, amount: i128) {
-    let topics = (Symbol::new(env, "payout"), owner.clone());
+pub(crate) fn payout_owner(env: &Env, owner: Address, token: Address, amount: i128) {
+    let topics = (Symbol::new(env, "payout"), owner.clone(), token);
 
     env.events().publish(
         topics,
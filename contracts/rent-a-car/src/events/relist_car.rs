@@ -0,0 +1,10 @@
+use soroban_sdk::{Env, Symbol};
+
+pub(crate) fn car_relisted(env: &Env, car_id: u32) {
+    let topics = (Symbol::new(env, "car_relisted"), car_id);
+
+    env.events().publish(
+        topics,
+        ()
+    );
+}
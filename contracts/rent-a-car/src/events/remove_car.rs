@@ -1,10 +1,10 @@
-﻿use soroban_sdk::{Address, Env, Symbol};
+﻿use soroban_sdk::{Env, Symbol};
 
-pub(crate) fn car_removed(env: &Env, owner: Address) {
-    let topics = (Symbol::new(env, "car_removed"), owner.clone());
+pub(crate) fn car_removed(env: &Env, car_id: u32) {
+    let topics = (Symbol::new(env, "car_removed"), car_id);
 
     env.events().publish(
         topics,
         ()
     );
-}
\ No newline at end of file
+}
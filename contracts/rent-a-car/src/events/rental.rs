@@ -1,26 +1,65 @@
 ﻿use soroban_sdk::{Address, Env, Symbol};
 
+use crate::storage::types::return_timing::ReturnTiming;
+
 pub(crate) fn rented(
     env: &Env,
     renter: Address,
-    owner: Address,
+    car_id: u32,
     total_days: u32,
-    amount: i128
+    amount: i128,
+    token: Address
 ) {
-    let topics = (Symbol::new(env, "rented"), renter.clone(), owner.clone());
+    let topics = (Symbol::new(env, "rented"), renter.clone(), car_id);
 
     env.events().publish(
         topics,
-        (total_days, amount)
+        (total_days, amount, token)
     );
 }
 
 pub(crate) fn car_returned(
     env: &Env,
     renter: Address,
-    owner: Address
+    car_id: u32,
+    timing: ReturnTiming,
+    refund: i128
+) {
+    let topics = (Symbol::new(env, "car_returned"), renter.clone(), car_id);
+
+    env.events().publish(topics, (timing, refund));
+}
+
+#[allow(deprecated)]
+pub(crate) fn extended(
+    env: &Env,
+    renter: Address,
+    car_id: u32,
+    extra_days: u32,
+    amount: i128,
+    due_ts: u64
+) {
+    let topics = (Symbol::new(env, "extended"), renter.clone(), car_id);
+
+    env.events().publish(
+        topics,
+        (extra_days, amount, due_ts)
+    );
+}
+
+#[allow(deprecated)]
+pub(crate) fn car_reclaimed(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    renter: Address,
+    rental_id: u32,
+    due_ts: u64
 ) {
-    let topics = (Symbol::new(env, "car_returned"), renter.clone(), owner.clone());
+    let topics = (Symbol::new(env, "car_reclaimed"), car_id, caller.clone());
 
-    env.events().publish(topics, ());
-}
\ No newline at end of file
+    env.events().publish(
+        topics,
+        (renter, rental_id, due_ts)
+    );
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+use crate::storage::types::role::Role;
+
+pub(crate) fn role_granted(env: &Env, role: Role, account: Address) {
+    let topics = (Symbol::new(env, "role_granted"), role, account.clone());
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn role_revoked(env: &Env, role: Role, account: Address) {
+    let topics = (Symbol::new(env, "role_revoked"), role, account.clone());
+
+    env.events().publish(topics, ());
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{Address, Env, Symbol};
+
+pub(crate) fn token_allowed(env: &Env, token: Address) {
+    let topics = (Symbol::new(env, "token_allowed"), token.clone());
+
+    env.events().publish(topics, ());
+}
+
+pub(crate) fn token_disallowed(env: &Env, token: Address) {
+    let topics = (Symbol::new(env, "token_disallowed"), token.clone());
+
+    env.events().publish(topics, ());
+}
@@ -0,0 +1,36 @@
+use soroban_sdk::{Address, Env, Symbol, Vec};
+
+use crate::storage::structs::{car_metadata::CarMetadata, car_token_price::CarTokenPrice};
+
+pub(crate) fn car_updated(
+    env: &Env,
+    caller: Address,
+    car_id: u32,
+    old_price_per_day: i128,
+    new_price_per_day: i128
+) {
+    let topics = (Symbol::new(env, "car_updated"), car_id, caller.clone());
+
+    env.events().publish(
+        topics,
+        (old_price_per_day, new_price_per_day)
+    );
+}
+
+pub(crate) fn car_metadata_updated(env: &Env, caller: Address, car_id: u32, metadata: CarMetadata) {
+    let topics = (Symbol::new(env, "car_metadata_updated"), car_id, caller.clone());
+
+    env.events().publish(topics, metadata);
+}
+
+pub(crate) fn car_tokens_updated(env: &Env, caller: Address, car_id: u32, tokens: Vec<Address>) {
+    let topics = (Symbol::new(env, "car_tokens_updated"), car_id, caller.clone());
+
+    env.events().publish(topics, tokens);
+}
+
+pub(crate) fn car_token_price_set(env: &Env, caller: Address, car_id: u32, token: Address, price: CarTokenPrice) {
+    let topics = (Symbol::new(env, "car_token_price_set"), car_id, caller.clone());
+
+    env.events().publish(topics, (token, price));
+}
@@ -1,23 +1,138 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::{Address, BytesN, Env, Vec};
 
-use crate::storage::types::{car_status::CarStatus, errors::Error};
+use crate::storage::structs::{booking::Booking, cancellation_policy::CancellationPolicy, car_details::CarDetails, car_metadata::CarMetadata, car_token_price::CarTokenPrice, commission::CommissionConfig, damage_claim::DamageClaim, deposit::Deposit, pending_admin::PendingAdmin, early_return_policy::EarlyReturnPolicy, handoff::Handoff, late_fee_policy::LateFeePolicy, legacy::LegacyCarRef, rental::Rental, rental_quote::RentalQuote, rental_record::RentalRecord};
+use crate::storage::types::{car_status::CarStatus, errors::Error, role::Role};
 
 pub trait RentACarContractTrait {
     fn __constructor(env: &Env, admin: Address, token: Address) -> Result<(), Error>;
-    fn add_car(env: &Env, owner: Address, price_per_day: i128) -> Result<(), Error>;
-    fn get_car_status(env: &Env, owener: Address) -> Result<CarStatus, Error>;
+    fn get_admin(env: &Env) -> Result<Address, Error>;
+    fn get_pending_admin(env: &Env) -> Option<PendingAdmin>;
+    fn propose_admin(env: &Env, new_admin: Address) -> Result<(), Error>;
+    fn accept_admin(env: &Env) -> Result<(), Error>;
+    fn renounce_admin(env: &Env) -> Result<(), Error>;
+    fn upgrade(env: &Env, new_wasm_hash: BytesN<32>) -> Result<(), Error>;
+    fn migrate(env: &Env) -> Result<u32, Error>;
+    fn get_schema_version(env: &Env) -> u32;
+    fn grant_role(env: &Env, account: Address, role: Role) -> Result<(), Error>;
+    fn revoke_role(env: &Env, account: Address, role: Role) -> Result<(), Error>;
+    fn has_role(env: &Env, account: Address, role: Role) -> bool;
+    fn pause(env: &Env, caller: Address) -> Result<(), Error>;
+    fn unpause(env: &Env, caller: Address) -> Result<(), Error>;
+    fn is_paused(env: &Env) -> bool;
+    fn allow_token(env: &Env, token: Address) -> Result<(), Error>;
+    fn disallow_token(env: &Env, token: Address) -> Result<(), Error>;
+    fn get_allowed_tokens(env: &Env) -> Result<Vec<Address>, Error>;
+    fn add_car(env: &Env, caller: Address, owner: Address, price_per_day: i128, metadata: CarMetadata) -> Result<u32, Error>;
+    fn get_car(env: &Env, car_id: u32) -> Result<CarDetails, Error>;
+    fn get_car_status(env: &Env, car_id: u32) -> Result<CarStatus, Error>;
+    fn get_car_count(env: &Env) -> u32;
+    fn list_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error>;
+    fn list_available_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error>;
     fn rental(
         env: &Env,
         renter: Address,
-        car_owner: Address,
+        car_id: u32,
         total_days_to_rent: u32,
         amount: i128,
+        token: Address,
     ) -> Result<(), Error>;
-    fn remove_car(env: &Env, owner: Address) -> Result<(), Error>;
-    fn payout_owner(env: &Env, owner: Address, amount: i128) -> Result<(), Error>;
-    fn set_admin_commission(env: &Env, commission: i128) -> Result<(), Error>;
-    fn withdraw_admin_commission(env: &Env, amount: i128) -> Result<(), Error>;
-    fn get_admin_available_to_withdraw(env: &Env) -> i128;
-    fn get_owner_available_to_withdraw(env: &Env, owner: Address) -> i128;
-    fn return_car(env: &Env, renter: Address, owner: Address) -> Result<(), Error>;
+    fn reserve(
+        env: &Env,
+        renter: Address,
+        car_id: u32,
+        start_ts: u64,
+        end_ts: u64,
+        amount: i128,
+        token: Address,
+    ) -> Result<u32, Error>;
+    fn start_reservation(env: &Env, booking_id: u32) -> Result<u32, Error>;
+    fn cancel_rental(env: &Env, renter: Address, booking_id: u32) -> Result<i128, Error>;
+    fn expire_booking(env: &Env, booking_id: u32) -> Result<i128, Error>;
+    fn set_cancellation_policy(env: &Env, full_refund_window: u64, partial_refund_bps: u32) -> Result<(), Error>;
+    fn get_cancellation_policy(env: &Env) -> CancellationPolicy;
+    fn get_booking(env: &Env, booking_id: u32) -> Result<Booking, Error>;
+    fn get_booking_token(env: &Env, booking_id: u32) -> Result<Address, Error>;
+    fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error>;
+    fn remove_car(env: &Env, caller: Address, car_id: u32) -> Result<(), Error>;
+    fn bump_car(env: &Env, car_id: u32) -> Result<(), Error>;
+    fn migrate_legacy_cars(env: &Env, cars: Vec<LegacyCarRef>) -> Result<u32, Error>;
+    fn relist_car(env: &Env, caller: Address, car_id: u32) -> Result<(), Error>;
+    fn update_car(env: &Env, caller: Address, car_id: u32, price_per_day: i128) -> Result<(), Error>;
+    fn update_car_metadata(env: &Env, caller: Address, car_id: u32, metadata: CarMetadata) -> Result<(), Error>;
+    fn payout_owner(env: &Env, owner: Address, amount: i128, token: Address) -> Result<(), Error>;
+    fn set_admin_commission(env: &Env, caller: Address, commission: i128) -> Result<(), Error>;
+    fn set_admin_commission_bps(env: &Env, caller: Address, bps: u32, min_fee: i128, max_fee: i128) -> Result<(), Error>;
+    fn get_admin_commission(env: &Env) -> CommissionConfig;
+    fn set_token_commission(env: &Env, caller: Address, token: Address, config: CommissionConfig) -> Result<(), Error>;
+    fn get_token_commission(env: &Env, token: Address) -> Result<CommissionConfig, Error>;
+    fn withdraw_admin_commission(env: &Env, caller: Address, amount: i128, token: Address) -> Result<(), Error>;
+    fn set_treasury(env: &Env, treasury: Address) -> Result<(), Error>;
+    fn get_treasury(env: &Env) -> Result<Address, Error>;
+    fn get_admin_available_to_withdraw(env: &Env, token: Address) -> i128;
+    fn get_owner_available_to_withdraw(env: &Env, owner: Address, token: Address) -> i128;
+    fn get_active_rental(env: &Env, car_id: u32) -> Result<Rental, Error>;
+    fn get_rental(env: &Env, rental_id: u32) -> Result<RentalRecord, Error>;
+    fn get_rental_token(env: &Env, rental_id: u32) -> Result<Address, Error>;
+    fn list_rentals_by_renter(env: &Env, renter: Address, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error>;
+    fn list_rentals_by_car(env: &Env, car_id: u32, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error>;
+    fn quote_rental(env: &Env, car_id: u32, total_days_to_rent: u32, token: Address) -> Result<RentalQuote, Error>;
+    fn extend_rental(env: &Env, renter: Address, car_id: u32, extra_days: u32) -> Result<(), Error>;
+    fn return_car(env: &Env, renter: Address, car_id: u32) -> Result<(), Error>;
+    fn force_return(env: &Env, caller: Address, car_id: u32) -> Result<(), Error>;
+    fn set_handoff_required(env: &Env, caller: Address, car_id: u32, required: bool) -> Result<(), Error>;
+    fn get_handoff_required(env: &Env, car_id: u32) -> Result<bool, Error>;
+    fn mark_handed_over(env: &Env, caller: Address, car_id: u32, odometer: u64, fuel_level: u32) -> Result<(), Error>;
+    fn confirm_pickup(env: &Env, renter: Address, car_id: u32) -> Result<(), Error>;
+    fn finalize_pickup(env: &Env, car_id: u32) -> Result<(), Error>;
+    fn cancel_pickup(env: &Env, car_id: u32) -> Result<i128, Error>;
+    fn confirm_return(env: &Env, caller: Address, car_id: u32, odometer: u64, fuel_level: u32) -> Result<(), Error>;
+    fn finalize_return(env: &Env, car_id: u32) -> Result<(), Error>;
+    fn get_handoff(env: &Env, rental_id: u32) -> Result<Handoff, Error>;
+    fn set_reclaim_grace_period(env: &Env, seconds: u64) -> Result<(), Error>;
+    fn get_reclaim_grace_period(env: &Env) -> u64;
+    fn set_car_deposit(env: &Env, caller: Address, car_id: u32, deposit_amount: i128) -> Result<(), Error>;
+    fn set_car_tokens(env: &Env, caller: Address, car_id: u32, tokens: Vec<Address>) -> Result<(), Error>;
+    fn get_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error>;
+    fn set_car_token_price(env: &Env, caller: Address, car_id: u32, token: Address, price: CarTokenPrice) -> Result<(), Error>;
+    fn get_car_token_price(env: &Env, car_id: u32, token: Address) -> Result<CarTokenPrice, Error>;
+    fn set_early_return_policy(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        policy: Option<EarlyReturnPolicy>,
+    ) -> Result<(), Error>;
+    fn get_early_return_policy(env: &Env, car_id: u32) -> Result<Option<EarlyReturnPolicy>, Error>;
+    fn set_late_fee_policy(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        policy: Option<LateFeePolicy>,
+    ) -> Result<(), Error>;
+    fn get_late_fee_policy(env: &Env, car_id: u32) -> Result<Option<LateFeePolicy>, Error>;
+    fn set_late_fee_platform_share(env: &Env, bps: u32) -> Result<(), Error>;
+    fn get_late_fee_platform_share(env: &Env) -> u32;
+    fn set_inspection_window(env: &Env, seconds: u64) -> Result<(), Error>;
+    fn release_deposit(env: &Env, rental_id: u32) -> Result<(), Error>;
+    fn get_deposit(env: &Env, rental_id: u32) -> Result<Deposit, Error>;
+    fn set_arbiter(env: &Env, arbiter: Address) -> Result<(), Error>;
+    fn get_arbiter(env: &Env) -> Result<Address, Error>;
+    fn file_damage_claim(
+        env: &Env,
+        owner: Address,
+        rental_id: u32,
+        amount: i128,
+        evidence_hash: BytesN<32>,
+    ) -> Result<(), Error>;
+    fn accept_damage_claim(env: &Env, renter: Address, rental_id: u32) -> Result<(), Error>;
+    fn contest_damage_claim(env: &Env, renter: Address, rental_id: u32) -> Result<(), Error>;
+    fn resolve_dispute(env: &Env, rental_id: u32, owner_amount: i128) -> Result<(), Error>;
+    fn get_damage_claim(env: &Env, rental_id: u32) -> Result<DamageClaim, Error>;
+    fn start_maintenance(
+        env: &Env,
+        caller: Address,
+        car_id: u32,
+        reason_code: u32,
+        document_hash: Option<BytesN<32>>,
+    ) -> Result<(), Error>;
+    fn end_maintenance(env: &Env, caller: Address, car_id: u32) -> Result<(), Error>;
 }
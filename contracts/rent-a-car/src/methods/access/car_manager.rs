@@ -0,0 +1,22 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::admin::read_admin;
+use crate::storage::structs::car::Car;
+use crate::storage::types::errors::Error;
+
+/// Car settings can be changed by the car's owner or by the contract admin.
+pub(crate) fn require_owner_or_admin(env: &Env, caller: &Address, car: &Car) -> Result<(), Error> {
+    caller.require_auth();
+
+    if *caller == car.owner {
+        return Ok(());
+    }
+
+    let admin = read_admin(env)?;
+
+    if *caller != admin {
+        return Err(Error::Unauthorized);
+    }
+
+    Ok(())
+}
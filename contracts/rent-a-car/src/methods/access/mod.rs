@@ -0,0 +1,3 @@
+pub mod car_manager;
+pub mod pause;
+pub mod role;
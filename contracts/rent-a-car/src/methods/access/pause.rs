@@ -0,0 +1,14 @@
+use soroban_sdk::Env;
+
+use crate::storage::pause::read_paused;
+use crate::storage::types::errors::Error;
+
+/// Guards new rentals, extensions and payouts. Returns, refunds and deposit releases are never
+/// paused, so renters can always hand a car back and recover their own funds.
+pub(crate) fn ensure_not_paused(env: &Env) -> Result<(), Error> {
+    if read_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::admin::read_admin;
+use crate::storage::role::has_role;
+use crate::storage::types::{errors::Error, role::Role};
+
+/// Privileged operations can be run by the root admin or by an account granted the matching role.
+/// Role holders keep their access if the admin is renounced.
+pub(crate) fn require_admin_or_role(env: &Env, caller: &Address, role: Role) -> Result<(), Error> {
+    caller.require_auth();
+
+    if read_admin(env).ok().as_ref() == Some(caller) || has_role(env, role, caller) {
+        return Ok(());
+    }
+
+    Err(Error::Unauthorized)
+}
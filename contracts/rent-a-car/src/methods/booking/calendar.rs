@@ -0,0 +1,27 @@
+use soroban_sdk::{Env, Vec};
+use crate::storage::booking::{read_booking, read_car_bookings};
+use crate::storage::structs::booking::Booking;
+use crate::storage::types::errors::Error;
+
+/// Upcoming reservations on `car_id` that overlap the half-open window `from..to`.
+pub(crate) fn bookings_in_window(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error> {
+    let mut bookings = Vec::new(env);
+
+    for booking_id in read_car_bookings(env, car_id).iter() {
+        let booking = read_booking(env, booking_id)?;
+
+        if booking.start_ts < to && from < booking.end_ts {
+            bookings.push_back(booking);
+        }
+    }
+
+    Ok(bookings)
+}
+
+pub(crate) fn ensure_window_is_free(env: &Env, car_id: u32, from: u64, to: u64) -> Result<(), Error> {
+    if !bookings_in_window(env, car_id, from, to)?.is_empty() {
+        return Err(Error::BookingOverlap);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+use soroban_sdk::Env;
+
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+use crate::methods::pricing::refund::share_of;
+use crate::methods::token::token::token_transfer;
+use crate::storage::admin::{read_admin_available_to_withdraw, write_admin_available_to_withdraw};
+use crate::storage::booking::{remove_car_booking, write_booking};
+use crate::storage::cancellation::read_cancellation_policy;
+use crate::storage::car::read_car;
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::deposit::{read_deposit_balance, write_deposit_balance};
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::rental::read_rental;
+use crate::storage::structs::booking::Booking;
+use crate::storage::token::read_booking_token;
+use crate::storage::types::{booking_status::BookingStatus, car_status::CarStatus, errors::Error};
+
+/// Share of a reservation's price and commission the cancellation policy refunds at `now`.
+pub(crate) fn cancellation_refund_bps(env: &Env, booking: &Booking, now: u64) -> Result<u32, Error> {
+    let policy = read_cancellation_policy(env);
+    let car_status = read_car(env, booking.car_id)?.car_status;
+
+    Ok(if !is_deliverable(env, &car_status, booking, now) || now.saturating_add(policy.full_refund_window) <= booking.start_ts {
+        BASIS_POINTS_DENOMINATOR as u32
+    } else if now < booking.start_ts {
+        policy.partial_refund_bps
+    } else {
+        0
+    })
+}
+
+/// Whether the renter could take the car at `now`. Before the window starts another rental may
+/// still be running, so only a car taken out of service counts as undeliverable. Afterwards, a
+/// rental that began before the window and is not over yet has kept the car from the renter.
+fn is_deliverable(env: &Env, car_status: &CarStatus, booking: &Booking, now: u64) -> bool {
+    match car_status {
+        CarStatus::Maintenance | CarStatus::Delisted => false,
+        CarStatus::Available => true,
+        _ => {
+            now < booking.start_ts
+                || read_rental(env, booking.car_id).map_or(true, |rental| rental.start_ts >= booking.start_ts)
+        }
+    }
+}
+
+/// Closes a reservation that never started with `status`. Refunds `refund_bps` of the price and
+/// commission plus the whole deposit; the rest is earned by the owner and the platform as if the
+/// rental had happened. Returns the refund, deposit excluded.
+pub(crate) fn settle_unstarted_booking(
+    env: &Env,
+    booking: &mut Booking,
+    refund_bps: u32,
+    status: BookingStatus,
+) -> Result<i128, Error> {
+    let refunded_price = share_of(booking.amount, refund_bps)?;
+    let refunded_commission = share_of(booking.commission, refund_bps)?;
+    let refund = refunded_price
+        .checked_add(refunded_commission)
+        .ok_or(Error::OverflowError)?;
+
+    let car = read_car(env, booking.car_id)?;
+    let token = read_booking_token(env, booking.booking_id)?;
+
+    let owner_available = read_owner_available_to_withdraw(env, &car.owner, &token)
+        .checked_add(booking.amount - refunded_price)
+        .ok_or(Error::OverflowError)?;
+
+    let admin_available = read_admin_available_to_withdraw(env, &token)
+        .checked_add(booking.commission - refunded_commission)
+        .ok_or(Error::OverflowError)?;
+
+    let contract_balance = read_contract_balance(env, &token)
+        .checked_sub(refund)
+        .ok_or(Error::OverflowError)?;
+
+    let deposit_balance = read_deposit_balance(env, &token)
+        .checked_sub(booking.deposit)
+        .ok_or(Error::OverflowError)?;
+
+    write_owner_available_to_withdraw(env, &car.owner, &token, owner_available);
+    write_admin_available_to_withdraw(env, &token, admin_available);
+    write_contract_balance(env, &token, &contract_balance);
+    write_deposit_balance(env, &token, &deposit_balance);
+    remove_car_booking(env, booking.car_id, booking.booking_id);
+
+    booking.status = status;
+    write_booking(env, booking.booking_id, booking);
+
+    let total_refund = refund
+        .checked_add(booking.deposit)
+        .ok_or(Error::OverflowError)?;
+
+    if total_refund > 0 {
+        token_transfer(env, &token, &env.current_contract_address(), &booking.renter, &total_refund)?;
+    }
+
+    Ok(refund)
+}
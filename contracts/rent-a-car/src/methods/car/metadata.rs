@@ -0,0 +1,27 @@
+use crate::constants::{MAX_CAR_NAME_LENGTH, MAX_CAR_SEATS, MAX_IMAGE_URI_LENGTH, MIN_CAR_YEAR};
+use crate::storage::structs::car_metadata::CarMetadata;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn validate_car_metadata(metadata: &CarMetadata) -> Result<(), Error> {
+    if metadata.make.is_empty() || metadata.make.len() > MAX_CAR_NAME_LENGTH {
+        return Err(Error::InvalidCarMetadata);
+    }
+
+    if metadata.model.is_empty() || metadata.model.len() > MAX_CAR_NAME_LENGTH {
+        return Err(Error::InvalidCarMetadata);
+    }
+
+    if metadata.image_uri.len() > MAX_IMAGE_URI_LENGTH {
+        return Err(Error::InvalidCarMetadata);
+    }
+
+    if metadata.year < MIN_CAR_YEAR {
+        return Err(Error::InvalidCarMetadata);
+    }
+
+    if metadata.seats == 0 || metadata.seats > MAX_CAR_SEATS {
+        return Err(Error::InvalidCarMetadata);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod release;
+pub mod settle;
@@ -0,0 +1,32 @@
+use soroban_sdk::Env;
+
+use crate::events;
+use crate::methods::token::token::token_transfer;
+use crate::storage::deposit::{read_deposit_balance, write_deposit, write_deposit_balance};
+use crate::storage::structs::deposit::Deposit;
+use crate::storage::token::read_rental_token;
+use crate::storage::types::deposit_status::DepositStatus;
+use crate::storage::types::errors::Error;
+
+/// Sends the whole escrowed deposit back to the renter and closes it.
+pub(crate) fn release_deposit_to_renter(env: &Env, rental_id: u32, deposit: &mut Deposit) -> Result<(), Error> {
+    let token = read_rental_token(env, rental_id)?;
+    let mut deposit_balance = read_deposit_balance(env, &token);
+
+    if deposit.amount > deposit_balance {
+        return Err(Error::BalanceNotAvailableForAmountRequested);
+    }
+
+    deposit_balance = deposit_balance
+        .checked_sub(deposit.amount)
+        .ok_or(Error::OverflowError)?;
+
+    deposit.status = DepositStatus::Released;
+
+    write_deposit_balance(env, &token, &deposit_balance);
+    write_deposit(env, rental_id, deposit);
+
+    token_transfer(env, &token, &env.current_contract_address(), &deposit.renter, &deposit.amount)?;
+    events::deposit::deposit_released(env, deposit.renter.clone(), rental_id, deposit.amount);
+    Ok(())
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::token::token::token_transfer;
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::deposit::{read_deposit_balance, write_deposit, write_deposit_balance};
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::structs::deposit::Deposit;
+use crate::storage::token::read_rental_token;
+use crate::storage::types::deposit_status::DepositStatus;
+use crate::storage::types::errors::Error;
+
+/// Splits an escrowed deposit: `owner_amount` joins the owner's withdrawable earnings and
+/// the remainder goes back to the renter. Returns the renter's share.
+pub(crate) fn settle_deposit(
+    env: &Env,
+    rental_id: u32,
+    deposit: &mut Deposit,
+    owner: &Address,
+    owner_amount: i128,
+) -> Result<i128, Error> {
+    if owner_amount < 0 || owner_amount > deposit.amount {
+        return Err(Error::ClaimExceedsDeposit);
+    }
+
+    let renter_amount = deposit
+        .amount
+        .checked_sub(owner_amount)
+        .ok_or(Error::OverflowError)?;
+
+    // Deposits are held in the token the rental was paid with
+    let token = read_rental_token(env, rental_id)?;
+    let mut deposit_balance = read_deposit_balance(env, &token);
+
+    if deposit.amount > deposit_balance {
+        return Err(Error::BalanceNotAvailableForAmountRequested);
+    }
+
+    deposit_balance = deposit_balance
+        .checked_sub(deposit.amount)
+        .ok_or(Error::OverflowError)?;
+
+    // The owner's share moves out of escrow and into the withdrawable pool
+    let contract_balance = read_contract_balance(env, &token)
+        .checked_add(owner_amount)
+        .ok_or(Error::OverflowError)?;
+    let owner_available = read_owner_available_to_withdraw(env, owner, &token)
+        .checked_add(owner_amount)
+        .ok_or(Error::OverflowError)?;
+
+    deposit.status = DepositStatus::Settled;
+
+    write_deposit_balance(env, &token, &deposit_balance);
+    write_contract_balance(env, &token, &contract_balance);
+    write_owner_available_to_withdraw(env, owner, &token, owner_available);
+    write_deposit(env, rental_id, deposit);
+
+    if renter_amount > 0 {
+        token_transfer(env, &token, &env.current_contract_address(), &deposit.renter, &renter_amount)?;
+    }
+
+    Ok(renter_amount)
+}
@@ -1,2 +1,9 @@
-﻿pub mod public;
+﻿pub mod access;
+pub mod booking;
+pub mod car;
+pub mod deposit;
+pub mod pricing;
+pub mod rental;
+pub mod storage;
+pub mod public;
 pub mod token;
@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::late_fee::read_late_fee_policy;
+use crate::storage::structs::car::Car;
+use crate::storage::structs::car_token_price::CarTokenPrice;
+use crate::storage::token::{read_car_token_price, read_token};
+use crate::storage::types::errors::Error;
+
+/// The car's amounts in `token`. The car record itself is priced in the default token; any other
+/// token needs a price set by the owner, and payments in a token without one are refused.
+pub(crate) fn car_price_in(env: &Env, car_id: u32, car: &Car, token: &Address) -> Result<CarTokenPrice, Error> {
+    if *token == read_token(env)? {
+        let late_fee = read_late_fee_policy(env, car_id)
+            .map(|policy| policy.fee)
+            .unwrap_or(0);
+
+        return Ok(CarTokenPrice {
+            price_per_day: car.price_per_day,
+            deposit_amount: car.deposit_amount,
+            late_fee,
+        });
+    }
+
+    read_car_token_price(env, car_id, token).ok_or(Error::TokenNotAllowed)
+}
@@ -0,0 +1,77 @@
+use soroban_sdk::{Address, Env};
+
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+use crate::storage::admin::{read_admin_commission, read_token_commission};
+use crate::storage::structs::commission::CommissionConfig;
+use crate::storage::token::read_token;
+use crate::storage::types::commission_mode::CommissionMode;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn validate_commission(config: &CommissionConfig) -> Result<(), Error> {
+    match config.mode {
+        CommissionMode::Flat => {
+            if config.flat_fee < 0 {
+                return Err(Error::AmountMustBePositive);
+            }
+        }
+        CommissionMode::BasisPoints => {
+            if config.bps as i128 > BASIS_POINTS_DENOMINATOR {
+                return Err(Error::InvalidCommission);
+            }
+
+            if config.min_fee < 0 || config.max_fee < 0 {
+                return Err(Error::AmountMustBePositive);
+            }
+
+            // A max_fee of 0 leaves the fee uncapped
+            if config.max_fee > 0 && config.max_fee < config.min_fee {
+                return Err(Error::InvalidCommission);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The commission charged on payments in `token`. The default config's fixed amounts are in the
+/// default token, so other tokens need their own config unless the default one is a pure rate.
+pub(crate) fn commission_config_in(env: &Env, token: &Address) -> Result<CommissionConfig, Error> {
+    let config = read_admin_commission(env);
+
+    if *token == read_token(env)? {
+        return Ok(config);
+    }
+
+    if let Some(token_config) = read_token_commission(env, token) {
+        return Ok(token_config);
+    }
+
+    if config.flat_fee == 0 && config.min_fee == 0 && config.max_fee == 0 {
+        return Ok(config);
+    }
+
+    Err(Error::TokenNotAllowed)
+}
+
+pub(crate) fn compute_commission(config: &CommissionConfig, base_price: i128) -> Result<i128, Error> {
+    match config.mode {
+        CommissionMode::Flat => Ok(config.flat_fee),
+        CommissionMode::BasisPoints => {
+            let mut fee = base_price
+                .checked_mul(config.bps as i128)
+                .ok_or(Error::OverflowError)?
+                .checked_div(BASIS_POINTS_DENOMINATOR)
+                .ok_or(Error::OverflowError)?;
+
+            if fee < config.min_fee {
+                fee = config.min_fee;
+            }
+
+            if config.max_fee > 0 && fee > config.max_fee {
+                fee = config.max_fee;
+            }
+
+            Ok(fee)
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use crate::constants::SECONDS_PER_DAY;
+use crate::storage::structs::{early_return_policy::EarlyReturnPolicy, rental::Rental};
+use crate::storage::types::errors::Error;
+
+/// Part of the rental amount refunded when the car comes back at `returned_ts`.
+/// Any started day counts as used, and the commission is never refunded.
+pub(crate) fn compute_early_return_refund(
+    rental: &Rental,
+    policy: &EarlyReturnPolicy,
+    returned_ts: u64,
+) -> Result<i128, Error> {
+    let elapsed = returned_ts.saturating_sub(rental.start_ts);
+    let used_days = elapsed.div_ceil(SECONDS_PER_DAY);
+
+    let charged_days = used_days
+        .max(policy.min_charged_days as u64)
+        .min(rental.total_days_to_rent as u64);
+    let unused_days = rental.total_days_to_rent as u64 - charged_days;
+
+    if unused_days == 0 {
+        return Ok(0);
+    }
+
+    rental.amount
+        .checked_mul(unused_days as i128)
+        .ok_or(Error::OverflowError)?
+        .checked_div(rental.total_days_to_rent as i128)
+        .ok_or(Error::OverflowError)
+}
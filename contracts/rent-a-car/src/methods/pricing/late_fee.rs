@@ -0,0 +1,23 @@
+use crate::constants::{SECONDS_PER_DAY, SECONDS_PER_HOUR};
+use crate::storage::structs::late_fee_policy::LateFeePolicy;
+use crate::storage::types::errors::Error;
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+
+/// Fee owed for a car returned at `returned_ts`; nothing inside the grace period.
+pub(crate) fn compute_late_fee(policy: &LateFeePolicy, due_ts: u64, returned_ts: u64) -> Result<i128, Error> {
+    let late_seconds = returned_ts.saturating_sub(due_ts);
+
+    if late_seconds <= policy.grace_period {
+        return Ok(0);
+    }
+
+    let unit_seconds = match policy.unit {
+        LateFeeUnit::Hour => SECONDS_PER_HOUR,
+        LateFeeUnit::Day => SECONDS_PER_DAY,
+    };
+    let late_units = (late_seconds - policy.grace_period).div_ceil(unit_seconds);
+
+    policy.fee
+        .checked_mul(late_units as i128)
+        .ok_or(Error::OverflowError)
+}
@@ -0,0 +1,6 @@
+pub mod car_price;
+pub mod commission;
+pub mod early_return;
+pub mod late_fee;
+pub mod refund;
+pub mod rental_quote;
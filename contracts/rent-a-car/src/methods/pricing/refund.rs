@@ -0,0 +1,11 @@
+use crate::constants::BASIS_POINTS_DENOMINATOR;
+use crate::storage::types::errors::Error;
+
+/// `bps` share of `amount`, rounded down.
+pub(crate) fn share_of(amount: i128, bps: u32) -> Result<i128, Error> {
+    amount
+        .checked_mul(bps as i128)
+        .ok_or(Error::OverflowError)?
+        .checked_div(BASIS_POINTS_DENOMINATOR)
+        .ok_or(Error::OverflowError)
+}
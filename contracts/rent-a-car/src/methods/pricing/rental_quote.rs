@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::pricing::car_price::car_price_in;
+use crate::methods::pricing::commission::{commission_config_in, compute_commission};
+use crate::storage::structs::car::Car;
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::types::errors::Error;
+
+/// Prices a rental from the car's daily rate in `token`; the renter's offered amount is never trusted.
+pub(crate) fn compute_rental_quote(
+    env: &Env,
+    car_id: u32,
+    car: &Car,
+    token: &Address,
+    total_days_to_rent: u32,
+) -> Result<RentalQuote, Error> {
+    if total_days_to_rent == 0 {
+        return Err(Error::RentalDurationCannotBeZero);
+    }
+
+    let price = car_price_in(env, car_id, car, token)?;
+
+    let base_price = price
+        .price_per_day
+        .checked_mul(total_days_to_rent as i128)
+        .ok_or(Error::OverflowError)?;
+
+    let commission = compute_commission(&commission_config_in(env, token)?, base_price)?;
+
+    let total = base_price
+        .checked_add(commission)
+        .ok_or(Error::OverflowError)?;
+
+    Ok(RentalQuote {
+        base_price,
+        commission,
+        total,
+        deposit: price.deposit_amount,
+    })
+}
@@ -0,0 +1,7 @@
+use soroban_sdk::Env;
+use crate::storage::admin::read_admin_commission;
+use crate::storage::structs::commission::CommissionConfig;
+
+pub(crate) fn get_admin_commission(env: &Env) -> CommissionConfig {
+    read_admin_commission(env)
+}
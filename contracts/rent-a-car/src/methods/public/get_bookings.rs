@@ -0,0 +1,25 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::methods::booking::calendar::bookings_in_window;
+use crate::storage::booking::read_booking;
+use crate::storage::car::has_car;
+use crate::storage::structs::booking::Booking;
+use crate::storage::token::read_booking_token;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_booking(env: &Env, booking_id: u32) -> Result<Booking, Error> {
+    read_booking(env, booking_id)
+}
+
+pub(crate) fn get_booking_token(env: &Env, booking_id: u32) -> Result<Address, Error> {
+    read_booking(env, booking_id)?;
+
+    read_booking_token(env, booking_id)
+}
+
+pub(crate) fn get_bookings(env: &Env, car_id: u32, from: u64, to: u64) -> Result<Vec<Booking>, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    bookings_in_window(env, car_id, from, to)
+}
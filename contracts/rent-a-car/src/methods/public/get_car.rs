@@ -0,0 +1,15 @@
+use soroban_sdk::Env;
+use crate::storage::car::{has_car, read_car, read_car_metadata};
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_car(env: &Env, car_id: u32) -> Result<CarDetails, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    let car = read_car(env, car_id)?;
+    let metadata = read_car_metadata(env, car_id)?;
+
+    Ok(CarDetails { car_id, car, metadata })
+}
@@ -1,14 +1,14 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::Env;
 use crate::storage::car::{has_car, read_car};
 use crate::storage::types::car_status::CarStatus;
 use crate::storage::types::errors::Error;
 
-pub(crate) fn get_car_status(env: &Env, owner: &Address) -> Result<CarStatus, Error> {
-    if !has_car(env, &owner) {
+pub(crate) fn get_car_status(env: &Env, car_id: u32) -> Result<CarStatus, Error> {
+    if !has_car(env, car_id) {
         return Err(Error::CarNotFound);
     }
 
-    let car = read_car(env, &owner)?;
+    let car = read_car(env, car_id)?;
 
     Ok(car.car_status)
-}
\ No newline at end of file
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::Env;
+use crate::storage::damage_claim::read_damage_claim;
+use crate::storage::structs::damage_claim::DamageClaim;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_damage_claim(env: &Env, rental_id: u32) -> Result<DamageClaim, Error> {
+    read_damage_claim(env, rental_id)
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::Env;
+use crate::storage::deposit::read_deposit;
+use crate::storage::structs::deposit::Deposit;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_deposit(env: &Env, rental_id: u32) -> Result<Deposit, Error> {
+    read_deposit(env, rental_id)
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::car::has_car;
+use crate::storage::rental::read_rental;
+use crate::storage::rental_history::read_rental_record;
+use crate::storage::structs::rental::Rental;
+use crate::storage::structs::rental_record::RentalRecord;
+use crate::storage::token::read_rental_token;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_active_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    read_rental(env, car_id)
+}
+
+pub(crate) fn get_rental(env: &Env, rental_id: u32) -> Result<RentalRecord, Error> {
+    read_rental_record(env, rental_id)
+}
+
+/// Rentals opened before multi-token payments read as paid in the default token.
+pub(crate) fn get_rental_token(env: &Env, rental_id: u32) -> Result<Address, Error> {
+    read_rental_record(env, rental_id)?;
+
+    read_rental_token(env, rental_id)
+}
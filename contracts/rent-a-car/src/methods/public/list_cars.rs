@@ -0,0 +1,41 @@
+use soroban_sdk::{Env, Vec};
+use crate::constants::MAX_PAGE_SIZE;
+use crate::storage::car::{read_car, read_car_metadata, read_next_car_id};
+use crate::storage::structs::car_details::CarDetails;
+use crate::storage::types::car_status::CarStatus;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn get_car_count(env: &Env) -> u32 {
+    read_next_car_id(env)
+}
+
+/// Returns the cars whose ids fall in `start..start + limit`, with `limit` capped at `MAX_PAGE_SIZE`.
+pub(crate) fn list_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error> {
+    list_page(env, start, limit, false)
+}
+
+/// Same id window as `list_cars`, keeping only cars that can be rented right now.
+/// A page may hold fewer than `limit` entries; continue from `start + limit`.
+pub(crate) fn list_available_cars(env: &Env, start: u32, limit: u32) -> Result<Vec<CarDetails>, Error> {
+    list_page(env, start, limit, true)
+}
+
+fn list_page(env: &Env, start: u32, limit: u32, only_available: bool) -> Result<Vec<CarDetails>, Error> {
+    let mut cars = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(read_next_car_id(env));
+
+    for car_id in start..end {
+        let car = read_car(env, car_id)?;
+
+        if only_available && car.car_status != CarStatus::Available {
+            continue;
+        }
+
+        let metadata = read_car_metadata(env, car_id)?;
+        cars.push_back(CarDetails { car_id, car, metadata });
+    }
+
+    Ok(cars)
+}
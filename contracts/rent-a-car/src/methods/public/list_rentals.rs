@@ -0,0 +1,41 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::constants::MAX_PAGE_SIZE;
+use crate::storage::car::has_car;
+use crate::storage::rental_history::{read_car_rental, read_car_rental_count, read_rental_record, read_renter_rental, read_renter_rental_count};
+use crate::storage::structs::rental_record::RentalRecord;
+use crate::storage::types::errors::Error;
+
+/// Returns the renter's rentals at history positions `start..start + limit`, oldest first,
+/// with `limit` capped at `MAX_PAGE_SIZE`.
+pub(crate) fn list_rentals_by_renter(env: &Env, renter: &Address, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error> {
+    let mut rentals = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(read_renter_rental_count(env, renter));
+
+    for index in start..end {
+        let rental_id = read_renter_rental(env, renter, index)?;
+        rentals.push_back(read_rental_record(env, rental_id)?);
+    }
+
+    Ok(rentals)
+}
+
+/// Same paging as `list_rentals_by_renter`, over every rental of a car.
+pub(crate) fn list_rentals_by_car(env: &Env, car_id: u32, start: u32, limit: u32) -> Result<Vec<RentalRecord>, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    let mut rentals = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(read_car_rental_count(env, car_id));
+
+    for index in start..end {
+        let rental_id = read_car_rental(env, car_id, index)?;
+        rentals.push_back(read_rental_record(env, rental_id)?);
+    }
+
+    Ok(rentals)
+}
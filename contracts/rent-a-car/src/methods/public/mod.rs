@@ -1,3 +1,12 @@
-﻿pub mod get_car_status;
+﻿pub mod get_car;
+pub mod get_bookings;
+pub mod get_car_status;
+pub mod get_damage_claim;
+pub mod get_deposit;
 pub mod get_admin_available_to_withdraw;
-pub mod get_owner_available_to_withdraw;
\ No newline at end of file
+pub mod get_admin_commission;
+pub mod get_owner_available_to_withdraw;
+pub mod get_rental;
+pub mod list_cars;
+pub mod list_rentals;
+pub mod quote_rental;
\ No newline at end of file
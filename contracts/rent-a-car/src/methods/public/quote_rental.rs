@@ -0,0 +1,15 @@
+use soroban_sdk::{Address, Env};
+use crate::methods::pricing::rental_quote::compute_rental_quote;
+use crate::storage::car::{has_car, read_car};
+use crate::storage::structs::rental_quote::RentalQuote;
+use crate::storage::types::errors::Error;
+
+pub(crate) fn quote_rental(env: &Env, car_id: u32, total_days_to_rent: u32, token: Address) -> Result<RentalQuote, Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    let car = read_car(env, car_id)?;
+
+    compute_rental_quote(env, car_id, &car, &token, total_days_to_rent)
+}
@@ -0,0 +1,152 @@
+use soroban_sdk::{Address, Env};
+
+use crate::events;
+use crate::methods::deposit::release::release_deposit_to_renter;
+use crate::methods::pricing::early_return::compute_early_return_refund;
+use crate::methods::pricing::late_fee::compute_late_fee;
+use crate::methods::rental::history::{record_late_fee, record_rental_closed};
+use crate::methods::rental::late_fee::collect_late_fee;
+use crate::methods::token::token::token_transfer;
+use crate::storage::car::write_car;
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::deposit::{has_deposit, read_deposit, read_inspection_window, write_deposit};
+use crate::storage::early_return::read_early_return_policy;
+use crate::storage::late_fee::read_late_fee_policy;
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::rental::remove_rental;
+use crate::storage::token::{read_car_token_price, read_rental_token, read_token};
+use crate::storage::structs::{car::Car, handoff::Handoff, rental::Rental};
+use crate::storage::types::{car_status::CarStatus, deposit_status::DepositStatus, errors::Error, rental_outcome::RentalOutcome, return_timing::ReturnTiming};
+
+/// Frees the car and credits the owner, refunding unused days when the car's policy allows it.
+/// Returns the timing of the return and the refund sent to the renter.
+pub(crate) fn close_rental(
+    env: &Env,
+    car_id: u32,
+    car: &mut Car,
+    rental: &Rental,
+    returned_ts: u64,
+    outcome: RentalOutcome,
+) -> Result<(ReturnTiming, i128), Error> {
+    let timing = ReturnTiming::from_timestamps(returned_ts, rental.due_ts);
+
+    let refund = match read_early_return_policy(env, car_id) {
+        Some(policy) if timing == ReturnTiming::Early => {
+            compute_early_return_refund(rental, &policy, returned_ts)?
+        }
+        _ => 0,
+    };
+
+    car.car_status = CarStatus::Available;
+
+    let token = read_rental_token(env, rental.rental_id)?;
+
+    // Owner is credited with the rental amount, less any early-return refund, once the car is back
+    let owner_amount = rental.amount
+        .checked_sub(refund)
+        .ok_or(Error::OverflowError)?;
+
+    let mut owner_available = read_owner_available_to_withdraw(env, &car.owner, &token);
+    owner_available = owner_available
+        .checked_add(owner_amount)
+        .ok_or(Error::OverflowError)?;
+
+    write_owner_available_to_withdraw(env, &car.owner, &token, owner_available);
+    write_car(env, car_id, car);
+    remove_rental(env, car_id);
+    record_rental_closed(env, rental.rental_id, returned_ts, refund, outcome)?;
+
+    if refund > 0 {
+        let contract_balance = read_contract_balance(env, &token)
+            .checked_sub(refund)
+            .ok_or(Error::OverflowError)?;
+
+        write_contract_balance(env, &token, &contract_balance);
+        token_transfer(env, &token, &env.current_contract_address(), &rental.renter, &refund)?;
+    }
+
+    Ok((timing, refund))
+}
+
+/// Applies the car's late-fee policy. Without `renter_pays` the fee is capped at the held deposit.
+pub(crate) fn charge_late_fee(
+    env: &Env,
+    car_id: u32,
+    rental: &Rental,
+    owner: &Address,
+    returned_ts: u64,
+    renter_pays: bool,
+) -> Result<(), Error> {
+    let late_fee = match read_late_fee_policy(env, car_id) {
+        Some(mut policy) => {
+            // The policy's fee is in the default token; rentals paid in another token use the car's price in it
+            let token = read_rental_token(env, rental.rental_id)?;
+
+            if token != read_token(env)? {
+                policy.fee = read_car_token_price(env, car_id, &token)
+                    .map(|price| price.late_fee)
+                    .unwrap_or(0);
+            }
+
+            compute_late_fee(&policy, rental.due_ts, returned_ts)?
+        }
+        None => 0,
+    };
+
+    if late_fee > 0 {
+        let (collected, from_deposit, platform_share) = collect_late_fee(env, rental, owner, late_fee, renter_pays)?;
+
+        if collected > 0 {
+            record_late_fee(env, rental.rental_id, collected)?;
+            events::late_fee::late_fee(env, rental.renter.clone(), car_id, collected, from_deposit, platform_share);
+        }
+    }
+
+    Ok(())
+}
+
+/// Starts the inspection window on the rental's deposit, or releases it straight away without one.
+pub(crate) fn hand_back_deposit(env: &Env, rental_id: u32) -> Result<(), Error> {
+    if !has_deposit(env, rental_id) {
+        return Ok(());
+    }
+
+    let mut deposit = read_deposit(env, rental_id)?;
+
+    // A deposit used up entirely by a late fee is already settled
+    if deposit.status != DepositStatus::Held {
+        return Ok(());
+    }
+
+    let inspection_window = read_inspection_window(env);
+
+    if inspection_window == 0 {
+        return release_deposit_to_renter(env, rental_id, &mut deposit);
+    }
+
+    deposit.status = DepositStatus::InInspection;
+    deposit.release_ts = env
+        .ledger()
+        .timestamp()
+        .checked_add(inspection_window)
+        .ok_or(Error::OverflowError)?;
+
+    write_deposit(env, rental_id, &deposit);
+    events::deposit::deposit_in_inspection(env, rental_id, deposit.release_ts);
+    Ok(())
+}
+
+/// Settles a handed-back car once the owner confirms the return or the confirmation times out.
+/// Timing and refunds are measured from when the renter handed the car back.
+pub(crate) fn settle_handoff_return(
+    env: &Env,
+    car_id: u32,
+    car: &mut Car,
+    rental: &Rental,
+    handoff: &Handoff,
+) -> Result<(), Error> {
+    let (timing, refund) = close_rental(env, car_id, car, rental, handoff.returned_ts, RentalOutcome::Returned)?;
+
+    events::rental::car_returned(env, rental.renter.clone(), car_id, timing, refund);
+    hand_back_deposit(env, rental.rental_id)
+}
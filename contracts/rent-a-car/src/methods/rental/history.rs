@@ -0,0 +1,78 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::rental_history::{push_car_rental, push_renter_rental, read_rental_record, write_rental_record};
+use crate::storage::structs::{rental::Rental, rental_record::RentalRecord};
+use crate::storage::types::{errors::Error, rental_outcome::RentalOutcome};
+
+/// Starts the permanent record of a rental and indexes it by renter and car.
+pub(crate) fn record_rental_opened(
+    env: &Env,
+    car_id: u32,
+    owner: &Address,
+    rental: &Rental,
+    commission: i128,
+) -> Result<(), Error> {
+    let record = RentalRecord {
+        rental_id: rental.rental_id,
+        car_id,
+        renter: rental.renter.clone(),
+        owner: owner.clone(),
+        total_days_to_rent: rental.total_days_to_rent,
+        amount: rental.amount,
+        commission,
+        deposit: rental.deposit,
+        start_ts: rental.start_ts,
+        due_ts: rental.due_ts,
+        returned_ts: 0,
+        refund: 0,
+        late_fee: 0,
+        outcome: RentalOutcome::Active,
+    };
+
+    write_rental_record(env, &record);
+    push_renter_rental(env, &rental.renter, rental.rental_id)?;
+    push_car_rental(env, car_id, rental.rental_id)
+}
+
+/// Copies an extended rental's new terms into its record, adding the extension's commission.
+pub(crate) fn record_rental_extended(env: &Env, rental: &Rental, commission: i128) -> Result<(), Error> {
+    let mut record = read_rental_record(env, rental.rental_id)?;
+
+    record.total_days_to_rent = rental.total_days_to_rent;
+    record.amount = rental.amount;
+    record.due_ts = rental.due_ts;
+    record.commission = record.commission
+        .checked_add(commission)
+        .ok_or(Error::OverflowError)?;
+
+    write_rental_record(env, &record);
+    Ok(())
+}
+
+pub(crate) fn record_rental_closed(
+    env: &Env,
+    rental_id: u32,
+    returned_ts: u64,
+    refund: i128,
+    outcome: RentalOutcome,
+) -> Result<(), Error> {
+    let mut record = read_rental_record(env, rental_id)?;
+
+    record.returned_ts = returned_ts;
+    record.refund = refund;
+    record.outcome = outcome;
+
+    write_rental_record(env, &record);
+    Ok(())
+}
+
+pub(crate) fn record_late_fee(env: &Env, rental_id: u32, late_fee: i128) -> Result<(), Error> {
+    let mut record = read_rental_record(env, rental_id)?;
+
+    record.late_fee = record.late_fee
+        .checked_add(late_fee)
+        .ok_or(Error::OverflowError)?;
+
+    write_rental_record(env, &record);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use soroban_sdk::{Address, Env};
+
+use crate::methods::pricing::refund::share_of;
+use crate::methods::token::token::token_transfer;
+use crate::storage::admin::{read_admin_available_to_withdraw, write_admin_available_to_withdraw};
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::deposit::{has_deposit, read_deposit, read_deposit_balance, write_deposit, write_deposit_balance};
+use crate::storage::late_fee::read_late_fee_platform_share;
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::structs::rental::Rental;
+use crate::storage::token::read_rental_token;
+use crate::storage::types::deposit_status::DepositStatus;
+use crate::storage::types::errors::Error;
+
+/// Collects `fee` from the rental's held deposit first and, with `renter_pays`, from the renter for the rest,
+/// then splits it between the owner and the platform. Returns `(collected, from_deposit, platform_share)`.
+pub(crate) fn collect_late_fee(
+    env: &Env,
+    rental: &Rental,
+    owner: &Address,
+    fee: i128,
+    renter_pays: bool,
+) -> Result<(i128, i128, i128), Error> {
+    let token = read_rental_token(env, rental.rental_id)?;
+    let mut from_deposit = 0;
+
+    if has_deposit(env, rental.rental_id) {
+        let mut deposit = read_deposit(env, rental.rental_id)?;
+
+        if deposit.status == DepositStatus::Held {
+            from_deposit = fee.min(deposit.amount);
+            deposit.amount = deposit.amount
+                .checked_sub(from_deposit)
+                .ok_or(Error::OverflowError)?;
+
+            // A fully consumed deposit has nothing left to inspect or release
+            if deposit.amount == 0 {
+                deposit.status = DepositStatus::Settled;
+            }
+
+            let deposit_balance = read_deposit_balance(env, &token)
+                .checked_sub(from_deposit)
+                .ok_or(Error::OverflowError)?;
+
+            write_deposit_balance(env, &token, &deposit_balance);
+            write_deposit(env, rental.rental_id, &deposit);
+        }
+    }
+
+    let from_renter = if renter_pays {
+        fee.checked_sub(from_deposit).ok_or(Error::OverflowError)?
+    } else {
+        0
+    };
+
+    if from_renter > 0 {
+        token_transfer(env, &token, &rental.renter, &env.current_contract_address(), &from_renter)?;
+    }
+
+    let fee = from_deposit
+        .checked_add(from_renter)
+        .ok_or(Error::OverflowError)?;
+
+    let platform_share = share_of(fee, read_late_fee_platform_share(env))?;
+    let owner_share = fee
+        .checked_sub(platform_share)
+        .ok_or(Error::OverflowError)?;
+
+    let contract_balance = read_contract_balance(env, &token)
+        .checked_add(fee)
+        .ok_or(Error::OverflowError)?;
+    let admin_available = read_admin_available_to_withdraw(env, &token)
+        .checked_add(platform_share)
+        .ok_or(Error::OverflowError)?;
+    let owner_available = read_owner_available_to_withdraw(env, owner, &token)
+        .checked_add(owner_share)
+        .ok_or(Error::OverflowError)?;
+
+    write_contract_balance(env, &token, &contract_balance);
+    write_admin_available_to_withdraw(env, &token, admin_available);
+    write_owner_available_to_withdraw(env, owner, &token, owner_available);
+
+    Ok((fee, from_deposit, platform_share))
+}
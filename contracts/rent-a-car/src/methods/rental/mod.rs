@@ -0,0 +1,4 @@
+pub mod close;
+pub mod history;
+pub mod late_fee;
+pub mod open;
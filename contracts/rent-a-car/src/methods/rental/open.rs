@@ -0,0 +1,87 @@
+use soroban_sdk::{Address, Env};
+use crate::methods::rental::history::record_rental_opened;
+use crate::storage::admin::{read_admin_available_to_withdraw, write_admin_available_to_withdraw};
+use crate::storage::car::write_car;
+use crate::storage::deposit::write_deposit;
+use crate::storage::handoff::{read_handoff_required, write_handoff};
+use crate::storage::rental::{read_next_rental_id, write_next_rental_id, write_rental};
+use crate::storage::rental_history::read_rental_record;
+use crate::storage::token::read_rental_token;
+use crate::storage::structs::{car::Car, deposit::Deposit, handoff::Handoff, rental::Rental};
+use crate::storage::types::{car_status::CarStatus, deposit_status::DepositStatus, errors::Error};
+
+pub(crate) fn allocate_rental_id(env: &Env) -> Result<u32, Error> {
+    let rental_id = read_next_rental_id(env);
+    let next_rental_id = rental_id.checked_add(1).ok_or(Error::OverflowError)?;
+
+    write_next_rental_id(env, next_rental_id);
+    Ok(rental_id)
+}
+
+/// Credits the platform's commission once the renter has the car. While a handoff is pending the
+/// rental can still be cancelled with a full refund, so `confirm_pickup` credits it instead.
+/// Starts a handed-over rental and credits the commission held back while the pickup was pending,
+/// extensions included.
+pub(crate) fn complete_pickup(env: &Env, car_id: u32, car: &mut Car, rental: &Rental) -> Result<(), Error> {
+    car.car_status = CarStatus::Rented;
+
+    let commission = read_rental_record(env, rental.rental_id)?.commission;
+    let token = read_rental_token(env, rental.rental_id)?;
+
+    write_car(env, car_id, car);
+    credit_commission(env, car, &token, commission)
+}
+
+pub(crate) fn credit_commission(env: &Env, car: &Car, token: &Address, commission: i128) -> Result<(), Error> {
+    if car.car_status == CarStatus::AwaitingPickup {
+        return Ok(());
+    }
+
+    let admin_available = read_admin_available_to_withdraw(env, token)
+        .checked_add(commission)
+        .ok_or(Error::OverflowError)?;
+
+    write_admin_available_to_withdraw(env, token, admin_available);
+    Ok(())
+}
+
+/// Marks the car as rented and records the rental with its held deposit. Cars that require a
+/// handoff wait for the owner and renter to confirm the pickup instead.
+/// Payment and balance accounting stay with the caller; `commission` is only recorded in the history.
+pub(crate) fn open_rental(env: &Env, car_id: u32, car: &mut Car, rental: &Rental, commission: i128) -> Result<(), Error> {
+    car.car_status = CarStatus::Rented;
+
+    if read_handoff_required(env, car_id) {
+        car.car_status = CarStatus::AwaitingPickup;
+
+        let handoff = Handoff {
+            car_id,
+            handed_over: false,
+            handed_over_ts: 0,
+            pickup_odometer: 0,
+            pickup_fuel_level: 0,
+            returned_ts: 0,
+            return_odometer: None,
+            return_fuel_level: None,
+        };
+
+        write_handoff(env, rental.rental_id, &handoff);
+    }
+
+    write_car(env, car_id, car);
+    write_rental(env, car_id, rental);
+
+    if rental.deposit > 0 {
+        let deposit = Deposit {
+            car_id,
+            renter: rental.renter.clone(),
+            amount: rental.deposit,
+            status: DepositStatus::Held,
+            release_ts: 0,
+        };
+
+        write_deposit(env, rental.rental_id, &deposit);
+    }
+
+    record_rental_opened(env, car_id, &car.owner, rental, commission)
+}
@@ -0,0 +1,31 @@
+use soroban_sdk::Env;
+
+use crate::storage::car::{car_entry_keys, has_car};
+use crate::storage::token::read_car_tokens;
+use crate::storage::ttl::extend_persistent_ttl;
+use crate::storage::types::errors::Error;
+use crate::storage::types::storage::DataKey;
+
+/// Extends the TTL of every entry kept for a car, so listings nobody touches are not archived.
+pub(crate) fn bump_car(env: &Env, car_id: u32) -> Result<(), Error> {
+    if !has_car(env, car_id) {
+        return Err(Error::CarNotFound);
+    }
+
+    for key in car_entry_keys(car_id) {
+        if env.storage().persistent().has(&key) {
+            extend_persistent_ttl(env, &key);
+        }
+    }
+
+    // Prices in other tokens are keyed by token, so they are found through the car's accepted tokens
+    for token in read_car_tokens(env, car_id)?.iter() {
+        let key = DataKey::CarTokenPrice(car_id, token);
+
+        if env.storage().persistent().has(&key) {
+            extend_persistent_ttl(env, &key);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{BytesN, Env, String, Vec};
+
+use crate::constants::{MAX_PAGE_SIZE, SECONDS_PER_DAY};
+use crate::events;
+use crate::methods::rental::history::record_rental_opened;
+use crate::methods::rental::open::allocate_rental_id;
+use crate::storage::car::{read_next_car_id, record_car_owner, write_car, write_car_metadata, write_next_car_id};
+use crate::storage::owner::{read_owner_available_to_withdraw, write_owner_available_to_withdraw};
+use crate::storage::rental::write_rental;
+use crate::storage::structs::{car::Car, car_metadata::CarMetadata, rental::Rental};
+use crate::storage::structs::legacy::{LegacyCarRef, OwnerKeyedCar, OwnerKeyedRental};
+use crate::storage::token::read_token;
+use crate::storage::types::{car_status::CarStatus, errors::Error, storage::LegacyDataKey};
+
+/// Converts the cars of the first release, which kept one car per owner under `Car(owner)` in
+/// instance storage and the owner's earnings on the car. Each car gets the next car id and blank
+/// metadata, and its earnings move to the owner's balance in the default token. Those keys cannot
+/// be listed on-chain, so the caller pages through the owners, at most `MAX_PAGE_SIZE` per call;
+/// a rented car also needs its renter, since its rental is keyed by both addresses.
+/// Owners with nothing left in the old layout are skipped, so a page can safely be sent again.
+/// Returns how many cars were converted.
+pub(crate) fn migrate_legacy_cars(env: &Env, cars: Vec<LegacyCarRef>) -> Result<u32, Error> {
+    let token = read_token(env)?;
+    let mut converted: u32 = 0;
+
+    for entry in cars.iter().take(MAX_PAGE_SIZE as usize) {
+        let car_key = LegacyDataKey::Car(entry.owner.clone());
+
+        let Some(legacy) = env.storage().instance().get::<_, OwnerKeyedCar>(&car_key) else {
+            continue;
+        };
+
+        let car_id = read_next_car_id(env);
+        let next_car_id = car_id.checked_add(1).ok_or(Error::OverflowError)?;
+
+        let car = Car {
+            owner: entry.owner.clone(),
+            price_per_day: legacy.price_per_day,
+            car_status: legacy.car_status.clone(),
+            deposit_amount: 0,
+        };
+
+        write_car(env, car_id, &car);
+        write_car_metadata(env, car_id, &blank_metadata(env));
+        write_next_car_id(env, next_car_id);
+        record_car_owner(env, &car.owner)?;
+
+        let mut earnings = legacy.available_to_withdraw;
+
+        if legacy.car_status == CarStatus::Rented {
+            let renter = entry.renter.clone().ok_or(Error::RentalNotFound)?;
+            let rental_key = LegacyDataKey::Rental(renter.clone(), entry.owner.clone());
+
+            let legacy_rental: OwnerKeyedRental = env.storage()
+                .instance()
+                .get(&rental_key)
+                .ok_or(Error::RentalNotFound)?;
+
+            // The first release credited the owner when the car was rented; now that happens on return
+            earnings = earnings
+                .checked_sub(legacy_rental.amount)
+                .ok_or(Error::OverflowError)?;
+
+            // Start times were never recorded, so the rental runs its full length from the migration
+            let start_ts = env.ledger().timestamp();
+            let rental_duration = (legacy_rental.total_days_to_rent as u64)
+                .checked_mul(SECONDS_PER_DAY)
+                .ok_or(Error::OverflowError)?;
+
+            let rental = Rental {
+                rental_id: allocate_rental_id(env)?,
+                renter,
+                total_days_to_rent: legacy_rental.total_days_to_rent,
+                amount: legacy_rental.amount,
+                deposit: 0,
+                start_ts,
+                due_ts: start_ts.checked_add(rental_duration).ok_or(Error::OverflowError)?,
+            };
+
+            write_rental(env, car_id, &rental);
+            record_rental_opened(env, car_id, &car.owner, &rental, 0)?;
+            env.storage().instance().remove(&rental_key);
+        }
+
+        let owner_available = read_owner_available_to_withdraw(env, &car.owner, &token)
+            .checked_add(earnings)
+            .ok_or(Error::OverflowError)?;
+
+        write_owner_available_to_withdraw(env, &car.owner, &token, owner_available);
+        env.storage().instance().remove(&car_key);
+
+        events::contract::legacy_car_migrated(env, car.owner, car_id);
+        converted += 1;
+    }
+
+    Ok(converted)
+}
+
+pub(crate) fn blank_metadata(env: &Env) -> CarMetadata {
+    CarMetadata {
+        make: String::from_str(env, ""),
+        model: String::from_str(env, ""),
+        year: 0,
+        seats: 0,
+        plate_hash: BytesN::from_array(env, &[0; 32]),
+        image_uri: String::from_str(env, ""),
+    }
+}
@@ -0,0 +1,3 @@
+pub mod bump;
+pub mod migrate;
+pub mod schema;
@@ -0,0 +1,65 @@
+use soroban_sdk::Env;
+
+use crate::constants::CURRENT_SCHEMA_VERSION;
+use crate::events;
+use crate::storage::schema::{read_schema_version, write_schema_version};
+use crate::storage::admin::{read_admin_available_to_withdraw, write_admin_available_to_withdraw};
+use crate::storage::contract_balance::{read_contract_balance, write_contract_balance};
+use crate::storage::token::read_token;
+use crate::storage::types::{errors::Error, storage::LegacyDataKey};
+
+/// Brings stored state up to the current schema version. The first release kept a single token,
+/// so its contract and admin balances move under the default token. Its cars are keyed by owner
+/// address and cannot be listed here; they are converted in pages by `migrate_legacy_cars`, which
+/// also moves each owner's earnings. A contract that is up to date returns right away, so running
+/// it twice is harmless. Returns how many entries were converted.
+pub(crate) fn migrate_schema(env: &Env) -> Result<u32, Error> {
+    let from_version = read_schema_version(env);
+
+    if from_version >= CURRENT_SCHEMA_VERSION {
+        return Ok(0);
+    }
+
+    let converted = move_legacy_balances(env)?;
+
+    write_schema_version(env, CURRENT_SCHEMA_VERSION);
+    events::contract::schema_migrated(env, from_version, CURRENT_SCHEMA_VERSION, converted);
+    Ok(converted)
+}
+
+/// Balances from before multi-token payments were all held in the default token. Amounts already
+/// credited under the new keys are kept and added to.
+fn move_legacy_balances(env: &Env) -> Result<u32, Error> {
+    let token = read_token(env)?;
+    let mut moved: u32 = 0;
+
+    if let Some(amount) = take_legacy_balance(env, &LegacyDataKey::ContractBalance) {
+        let balance = read_contract_balance(env, &token)
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+
+        write_contract_balance(env, &token, &balance);
+        moved += 1;
+    }
+
+    if let Some(amount) = take_legacy_balance(env, &LegacyDataKey::AdminAvailableToWithdraw) {
+        let balance = read_admin_available_to_withdraw(env, &token)
+            .checked_add(amount)
+            .ok_or(Error::OverflowError)?;
+
+        write_admin_available_to_withdraw(env, &token, balance);
+        moved += 1;
+    }
+
+    Ok(moved)
+}
+
+/// Removes a legacy balance wherever it was kept, in instance or persistent storage.
+fn take_legacy_balance(env: &Env, key: &LegacyDataKey) -> Option<i128> {
+    let amount = env.storage().persistent().get(key)
+        .or_else(|| env.storage().instance().get(key));
+
+    env.storage().persistent().remove(key);
+    env.storage().instance().remove(key);
+    amount
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::token::{is_token_allowed, read_car_tokens};
+use crate::storage::types::errors::Error;
+
+/// New payments must use a token the admin allows and the car's owner accepts.
+/// Withdrawals and refunds skip this check, so dropping a token never strands its balances.
+pub(crate) fn ensure_token_accepted(env: &Env, car_id: u32, token: &Address) -> Result<(), Error> {
+    if !is_token_allowed(env, token)? || !read_car_tokens(env, car_id)?.contains(token) {
+        return Err(Error::TokenNotAllowed);
+    }
+
+    Ok(())
+}
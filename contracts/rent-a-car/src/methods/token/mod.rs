@@ -1 +1,2 @@
-﻿pub mod token;
\ No newline at end of file
+﻿pub mod accepted;
+pub mod token;
\ No newline at end of file
@@ -3,12 +3,10 @@
     Address, Env,
 };
 
-use crate::storage::token::read_token;
 use crate::storage::types::errors::Error;
 
-pub fn token_transfer(env: &Env, from: &Address, to: &Address, amount: &i128) ->Result<(), Error> {
-    let token_address = read_token(env)?;
-    let token = token::TokenClient::new(env, &token_address);
+pub fn token_transfer(env: &Env, token_address: &Address, from: &Address, to: &Address, amount: &i128) ->Result<(), Error> {
+    let token = token::TokenClient::new(env, token_address);
     token.transfer(from, to, amount);
 
     Ok(())
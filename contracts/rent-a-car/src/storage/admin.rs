@@ -1,6 +1,10 @@
 ﻿use soroban_sdk::{symbol_short, Address, Env, Symbol};
+use crate::storage::structs::commission::CommissionConfig;
+use crate::storage::structs::pending_admin::PendingAdmin;
+use crate::storage::types::commission_mode::CommissionMode;
 use crate::storage::types::errors::Error;
 use super::types::storage::DataKey;
+use crate::storage::ttl::write_instance;
 
 
 pub const ADMIN_KEY: &Symbol = &symbol_short!("ADMIN");
@@ -21,26 +25,99 @@ pub(crate) fn read_admin(env: &Env) -> Result<Address, Error> {
 pub(crate) fn write_admin(env: &Env, admin: &Address) {
     let key = DataKey::Admin;
 
-    env.storage().instance().set(&key, admin);
+    write_instance(env, &key, admin);
 }
 
-pub(crate) fn read_admin_commission(env: &Env) -> i128 {
-    let key = DataKey::AdminCommission;
-    
-    env.storage()
+pub(crate) fn remove_admin(env: &Env) {
+    let key = DataKey::Admin;
+
+    env.storage().instance().remove(&key);
+}
+
+/// Proposals past their expiry are treated as if they were never made.
+pub(crate) fn read_pending_admin(env: &Env) -> Option<PendingAdmin> {
+    let key = DataKey::PendingAdmin;
+    let pending_admin: PendingAdmin = env.storage().instance().get(&key)?;
+
+    if env.ledger().timestamp() > pending_admin.expires_ts {
+        return None;
+    }
+
+    Some(pending_admin)
+}
+
+pub(crate) fn write_pending_admin(env: &Env, pending_admin: &PendingAdmin) {
+    let key = DataKey::PendingAdmin;
+
+    write_instance(env, &key, pending_admin);
+}
+
+pub(crate) fn remove_pending_admin(env: &Env) {
+    let key = DataKey::PendingAdmin;
+
+    env.storage().instance().remove(&key);
+}
+
+/// Where withdrawn commission is sent. Without a configured treasury it goes to the admin.
+pub(crate) fn read_treasury(env: &Env) -> Result<Address, Error> {
+    let key = DataKey::Treasury;
+
+    match env.storage().instance().get(&key) {
+        Some(treasury) => Ok(treasury),
+        None => read_admin(env),
+    }
+}
+
+pub(crate) fn write_treasury(env: &Env, treasury: &Address) {
+    let key = DataKey::Treasury;
+
+    write_instance(env, &key, treasury);
+}
+
+pub(crate) fn read_admin_commission(env: &Env) -> CommissionConfig {
+    let key = DataKey::CommissionConfig;
+
+    if let Some(config) = env.storage().instance().get(&key) {
+        return config;
+    }
+
+    // Deployments configured before fee modes existed only stored a flat amount
+    let legacy_key = DataKey::AdminCommission;
+    let flat_fee: i128 = env.storage()
         .instance()
-        .get(&key)
-        .unwrap_or(0)
+        .get(&legacy_key)
+        .unwrap_or(0);
+
+    CommissionConfig {
+        mode: CommissionMode::Flat,
+        flat_fee,
+        bps: 0,
+        min_fee: 0,
+        max_fee: 0,
+    }
+}
+
+/// Commission configured for one payment token, overriding the default one for payments in it.
+pub(crate) fn read_token_commission(env: &Env, token: &Address) -> Option<CommissionConfig> {
+    let key = DataKey::TokenCommission(token.clone());
+
+    env.storage().instance().get(&key)
+}
+
+pub(crate) fn write_token_commission(env: &Env, token: &Address, config: &CommissionConfig) {
+    let key = DataKey::TokenCommission(token.clone());
+
+    write_instance(env, &key, config);
 }
 
-pub(crate) fn write_admin_commission(env: &Env, commission: i128) {
-    let key = DataKey::AdminCommission;
+pub(crate) fn write_admin_commission(env: &Env, config: &CommissionConfig) {
+    let key = DataKey::CommissionConfig;
     
-    env.storage().instance().set(&key, &commission);
+    write_instance(env, &key, config);
 }
 
-pub(crate) fn read_admin_available_to_withdraw(env: &Env) -> i128 {
-    let key = DataKey::AdminAvailableToWithdraw;
+pub(crate) fn read_admin_available_to_withdraw(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::AdminAvailableToWithdraw(token.clone());
     
     env.storage()
         .instance()
@@ -48,8 +125,8 @@ pub(crate) fn read_admin_available_to_withdraw(env: &Env) -> i128 {
         .unwrap_or(0)
 }
 
-pub(crate) fn write_admin_available_to_withdraw(env: &Env, amount: i128) {
-    let key = DataKey::AdminAvailableToWithdraw;
+pub(crate) fn write_admin_available_to_withdraw(env: &Env, token: &Address, amount: i128) {
+    let key = DataKey::AdminAvailableToWithdraw(token.clone());
     
-    env.storage().instance().set(&key, &amount);
+    write_instance(env, &key, &amount);
 }
\ No newline at end of file
@@ -0,0 +1,16 @@
+use soroban_sdk::{Address, Env};
+use crate::storage::types::errors::Error;
+use super::types::storage::DataKey;
+use crate::storage::ttl::write_instance;
+
+pub(crate) fn read_arbiter(env: &Env) -> Result<Address, Error> {
+    let key = DataKey::Arbiter;
+
+    env.storage().instance().get(&key).ok_or(Error::ArbiterNotFound)
+}
+
+pub(crate) fn write_arbiter(env: &Env, arbiter: &Address) {
+    let key = DataKey::Arbiter;
+
+    write_instance(env, &key, arbiter);
+}
@@ -0,0 +1,44 @@
+use soroban_sdk::{Env, Vec};
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::booking::Booking, types::storage::DataKey};
+use crate::storage::ttl::{read_persistent, write_instance, write_persistent};
+
+pub(crate) fn read_booking(env: &Env, booking_id: u32) -> Result<Booking, Error> {
+    read_persistent(env, &DataKey::Booking(booking_id))
+        .ok_or(Error::BookingNotFound)
+}
+
+pub(crate) fn write_booking(env: &Env, booking_id: u32, booking: &Booking) {
+    write_persistent(env, &DataKey::Booking(booking_id), booking);
+}
+
+pub(crate) fn read_next_booking_id(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::NextBookingId)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_next_booking_id(env: &Env, next_booking_id: u32) {
+    write_instance(env, &DataKey::NextBookingId, &next_booking_id);
+}
+
+/// Ids of the reservations on a car that have not started yet.
+pub(crate) fn read_car_bookings(env: &Env, car_id: u32) -> Vec<u32> {
+    read_persistent(env, &DataKey::CarBookings(car_id))
+        .unwrap_or(Vec::new(env))
+}
+
+pub(crate) fn write_car_bookings(env: &Env, car_id: u32, booking_ids: &Vec<u32>) {
+    write_persistent(env, &DataKey::CarBookings(car_id), booking_ids);
+}
+
+pub(crate) fn remove_car_booking(env: &Env, car_id: u32, booking_id: u32) {
+    let mut booking_ids = read_car_bookings(env, car_id);
+
+    if let Some(index) = booking_ids.first_index_of(booking_id) {
+        booking_ids.remove(index);
+        write_car_bookings(env, car_id, &booking_ids);
+    }
+}
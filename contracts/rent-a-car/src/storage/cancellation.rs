@@ -0,0 +1,19 @@
+use soroban_sdk::Env;
+
+use crate::constants::{DEFAULT_FULL_REFUND_WINDOW, DEFAULT_PARTIAL_REFUND_BPS};
+use crate::storage::{structs::cancellation_policy::CancellationPolicy, types::storage::DataKey};
+use crate::storage::ttl::write_instance;
+
+pub(crate) fn read_cancellation_policy(env: &Env) -> CancellationPolicy {
+    env.storage()
+        .instance()
+        .get(&DataKey::CancellationPolicy)
+        .unwrap_or(CancellationPolicy {
+            full_refund_window: DEFAULT_FULL_REFUND_WINDOW,
+            partial_refund_bps: DEFAULT_PARTIAL_REFUND_BPS,
+        })
+}
+
+pub(crate) fn write_cancellation_policy(env: &Env, policy: &CancellationPolicy) {
+    write_instance(env, &DataKey::CancellationPolicy, policy);
+}
@@ -1,27 +1,66 @@
 ﻿use soroban_sdk::{Address, Env};
 
 use crate::storage::types::errors::Error;
-use crate::storage::{structs::car::Car, types::storage::DataKey};
+use crate::storage::{structs::{car::Car, car_metadata::CarMetadata}, types::storage::DataKey};
+use crate::storage::ttl::{has_persistent, read_persistent, write_instance, write_persistent};
 
-pub(crate) fn has_car(env: &Env, owner: &Address) -> bool {
-    env.storage().instance().has(&DataKey::Car(owner.clone()))
+pub(crate) fn has_car(env: &Env, car_id: u32) -> bool {
+    has_persistent(env, &DataKey::Car(car_id))
 }
 
-pub(crate) fn read_car(env: &Env, owner: &Address) -> Result<Car, Error> {
-    env.storage()
-        .instance()
-        .get(&DataKey::Car(owner.clone()))
+pub(crate) fn read_car(env: &Env, car_id: u32) -> Result<Car, Error> {
+    read_persistent(env, &DataKey::Car(car_id))
         .ok_or(Error::CarNotFound)
 }
 
-pub(crate) fn write_car(env: &Env, owner: &Address, car: &Car) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Car(owner.clone()), car);
+pub(crate) fn write_car(env: &Env, car_id: u32, car: &Car) {
+    write_persistent(env, &DataKey::Car(car_id), car);
 }
 
-pub(crate) fn remove_car(env: &Env, owner: &Address) {
+pub(crate) fn read_next_car_id(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .remove(&DataKey::Car(owner.clone()));
+        .get(&DataKey::NextCarId)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_next_car_id(env: &Env, next_car_id: u32) {
+    write_instance(env, &DataKey::NextCarId, &next_car_id);
+}
+
+/// Whether `owner` has ever had a car listed. Cars never change owner, so the count only grows.
+pub(crate) fn is_car_owner(env: &Env, owner: &Address) -> bool {
+    read_persistent::<u32>(env, &DataKey::OwnerCarCount(owner.clone()))
+        .is_some_and(|count| count > 0)
+}
+
+pub(crate) fn record_car_owner(env: &Env, owner: &Address) -> Result<(), Error> {
+    let key = DataKey::OwnerCarCount(owner.clone());
+    let count: u32 = read_persistent(env, &key).unwrap_or(0);
+
+    write_persistent(env, &key, &count.checked_add(1).ok_or(Error::OverflowError)?);
+    Ok(())
+}
+
+pub(crate) fn read_car_metadata(env: &Env, car_id: u32) -> Result<CarMetadata, Error> {
+    read_persistent(env, &DataKey::CarMetadata(car_id))
+        .ok_or(Error::CarNotFound)
+}
+
+pub(crate) fn write_car_metadata(env: &Env, car_id: u32, metadata: &CarMetadata) {
+    write_persistent(env, &DataKey::CarMetadata(car_id), metadata);
+}
+
+/// Entries stored per car, kept alive together by `bump_car`.
+pub(crate) fn car_entry_keys(car_id: u32) -> [DataKey; 8] {
+    [
+        DataKey::Car(car_id),
+        DataKey::CarMetadata(car_id),
+        DataKey::CarBookings(car_id),
+        DataKey::Rental(car_id),
+        DataKey::EarlyReturnPolicy(car_id),
+        DataKey::LateFeePolicy(car_id),
+        DataKey::HandoffRequired(car_id),
+        DataKey::CarTokens(car_id),
+    ]
 }
@@ -1,16 +1,13 @@
-﻿use soroban_sdk::Env;
+﻿use soroban_sdk::{Address, Env};
 
 use crate::storage::types::storage::DataKey;
+use crate::storage::ttl::{read_persistent, write_persistent};
 
-pub fn read_contract_balance(env: &Env) -> i128 {
-    env.storage()
-        .persistent()
-        .get(&DataKey::ContractBalance)
+pub fn read_contract_balance(env: &Env, token: &Address) -> i128 {
+    read_persistent(env, &DataKey::ContractBalance(token.clone()))
         .unwrap_or(0)
 }
 
-pub fn write_contract_balance(env: &Env, amount: &i128) {
-    env.storage()
-        .persistent()
-        .set(&DataKey::ContractBalance, amount);
+pub fn write_contract_balance(env: &Env, token: &Address, amount: &i128) {
+    write_persistent(env, &DataKey::ContractBalance(token.clone()), amount);
 }
\ No newline at end of file
@@ -0,0 +1,18 @@
+use soroban_sdk::Env;
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::damage_claim::DamageClaim, types::storage::DataKey};
+use crate::storage::ttl::{has_persistent, read_persistent, write_persistent};
+
+pub(crate) fn has_damage_claim(env: &Env, rental_id: u32) -> bool {
+    has_persistent(env, &DataKey::DamageClaim(rental_id))
+}
+
+pub(crate) fn read_damage_claim(env: &Env, rental_id: u32) -> Result<DamageClaim, Error> {
+    read_persistent(env, &DataKey::DamageClaim(rental_id))
+        .ok_or(Error::DamageClaimNotFound)
+}
+
+pub(crate) fn write_damage_claim(env: &Env, rental_id: u32, claim: &DamageClaim) {
+    write_persistent(env, &DataKey::DamageClaim(rental_id), claim);
+}
@@ -0,0 +1,40 @@
+use soroban_sdk::{Address, Env};
+
+use crate::constants::DEFAULT_INSPECTION_WINDOW;
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::deposit::Deposit, types::storage::DataKey};
+use crate::storage::ttl::{has_persistent, read_persistent, write_instance, write_persistent};
+
+/// Deposits are escrowed apart from `ContractBalance` so they never fund payouts.
+pub fn read_deposit_balance(env: &Env, token: &Address) -> i128 {
+    read_persistent(env, &DataKey::DepositBalance(token.clone()))
+        .unwrap_or(0)
+}
+
+pub fn write_deposit_balance(env: &Env, token: &Address, amount: &i128) {
+    write_persistent(env, &DataKey::DepositBalance(token.clone()), amount);
+}
+
+pub(crate) fn has_deposit(env: &Env, rental_id: u32) -> bool {
+    has_persistent(env, &DataKey::Deposit(rental_id))
+}
+
+pub(crate) fn read_deposit(env: &Env, rental_id: u32) -> Result<Deposit, Error> {
+    read_persistent(env, &DataKey::Deposit(rental_id))
+        .ok_or(Error::DepositNotFound)
+}
+
+pub(crate) fn write_deposit(env: &Env, rental_id: u32, deposit: &Deposit) {
+    write_persistent(env, &DataKey::Deposit(rental_id), deposit);
+}
+
+pub(crate) fn read_inspection_window(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::InspectionWindow)
+        .unwrap_or(DEFAULT_INSPECTION_WINDOW)
+}
+
+pub(crate) fn write_inspection_window(env: &Env, seconds: u64) {
+    write_instance(env, &DataKey::InspectionWindow, &seconds);
+}
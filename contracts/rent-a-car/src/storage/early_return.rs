@@ -0,0 +1,16 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::early_return_policy::EarlyReturnPolicy, types::storage::DataKey};
+use crate::storage::ttl::{read_persistent, remove_persistent, write_persistent};
+
+pub(crate) fn read_early_return_policy(env: &Env, car_id: u32) -> Option<EarlyReturnPolicy> {
+    read_persistent(env, &DataKey::EarlyReturnPolicy(car_id))
+}
+
+pub(crate) fn write_early_return_policy(env: &Env, car_id: u32, policy: &EarlyReturnPolicy) {
+    write_persistent(env, &DataKey::EarlyReturnPolicy(car_id), policy);
+}
+
+pub(crate) fn remove_early_return_policy(env: &Env, car_id: u32) {
+    remove_persistent(env, &DataKey::EarlyReturnPolicy(car_id));
+}
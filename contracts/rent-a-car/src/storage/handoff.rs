@@ -0,0 +1,27 @@
+use soroban_sdk::Env;
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::handoff::Handoff, types::storage::DataKey};
+use crate::storage::ttl::{has_persistent, read_persistent, write_persistent};
+
+pub(crate) fn read_handoff_required(env: &Env, car_id: u32) -> bool {
+    read_persistent(env, &DataKey::HandoffRequired(car_id))
+        .unwrap_or(false)
+}
+
+pub(crate) fn write_handoff_required(env: &Env, car_id: u32, required: bool) {
+    write_persistent(env, &DataKey::HandoffRequired(car_id), &required);
+}
+
+pub(crate) fn has_handoff(env: &Env, rental_id: u32) -> bool {
+    has_persistent(env, &DataKey::Handoff(rental_id))
+}
+
+pub(crate) fn read_handoff(env: &Env, rental_id: u32) -> Result<Handoff, Error> {
+    read_persistent(env, &DataKey::Handoff(rental_id))
+        .ok_or(Error::HandoffNotFound)
+}
+
+pub(crate) fn write_handoff(env: &Env, rental_id: u32, handoff: &Handoff) {
+    write_persistent(env, &DataKey::Handoff(rental_id), handoff);
+}
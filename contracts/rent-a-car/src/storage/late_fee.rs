@@ -0,0 +1,28 @@
+use soroban_sdk::Env;
+
+use crate::storage::{structs::late_fee_policy::LateFeePolicy, types::storage::DataKey};
+use crate::storage::ttl::{read_persistent, remove_persistent, write_instance, write_persistent};
+
+pub(crate) fn read_late_fee_policy(env: &Env, car_id: u32) -> Option<LateFeePolicy> {
+    read_persistent(env, &DataKey::LateFeePolicy(car_id))
+}
+
+pub(crate) fn write_late_fee_policy(env: &Env, car_id: u32, policy: &LateFeePolicy) {
+    write_persistent(env, &DataKey::LateFeePolicy(car_id), policy);
+}
+
+pub(crate) fn remove_late_fee_policy(env: &Env, car_id: u32) {
+    remove_persistent(env, &DataKey::LateFeePolicy(car_id));
+}
+
+/// Basis points of every late fee kept by the platform.
+pub(crate) fn read_late_fee_platform_share(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::LateFeePlatformShare)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_late_fee_platform_share(env: &Env, bps: u32) {
+    write_instance(env, &DataKey::LateFeePlatformShare, &bps);
+}
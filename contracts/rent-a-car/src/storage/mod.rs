@@ -1,7 +1,21 @@
 ﻿pub mod admin;
+pub mod arbiter;
+pub mod booking;
+pub mod cancellation;
 pub mod car;
 pub mod contract_balance;
+pub mod damage_claim;
+pub mod deposit;
+pub mod early_return;
+pub mod handoff;
+pub mod late_fee;
+pub mod owner;
+pub mod pause;
 pub mod rental;
+pub mod rental_history;
+pub mod role;
+pub mod schema;
 pub mod structs;
 pub mod token;
+pub mod ttl;
 pub mod types;
@@ -0,0 +1,16 @@
+﻿use soroban_sdk::{Address, Env};
+
+use crate::storage::types::storage::DataKey;
+use crate::storage::ttl::{read_persistent, write_persistent};
+
+pub(crate) fn read_owner_available_to_withdraw(env: &Env, owner: &Address, token: &Address) -> i128 {
+    let key = DataKey::OwnerAvailableToWithdraw(owner.clone(), token.clone());
+
+    read_persistent(env, &key).unwrap_or(0)
+}
+
+pub(crate) fn write_owner_available_to_withdraw(env: &Env, owner: &Address, token: &Address, amount: i128) {
+    let key = DataKey::OwnerAvailableToWithdraw(owner.clone(), token.clone());
+
+    write_persistent(env, &key, &amount);
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::Env;
+
+use crate::storage::types::storage::DataKey;
+use crate::storage::ttl::write_instance;
+
+pub(crate) fn read_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Paused)
+        .unwrap_or(false)
+}
+
+pub(crate) fn write_paused(env: &Env, paused: bool) {
+    write_instance(env, &DataKey::Paused, &paused);
+}
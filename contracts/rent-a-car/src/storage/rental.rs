@@ -1,33 +1,45 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::Env;
 
+use crate::constants::DEFAULT_RECLAIM_GRACE_PERIOD;
 use crate::storage::types::errors::Error;
 use crate::storage::{structs::rental::Rental, types::storage::DataKey};
+use crate::storage::ttl::{has_persistent, read_persistent, remove_persistent, write_instance, write_persistent};
 
-pub(crate) fn has_rental(env: &Env, renter: &Address, car_owner: &Address) -> bool {
-    env.storage()
-        .instance()
-        .has(&DataKey::Rental(renter.clone(), car_owner.clone()))
+pub(crate) fn has_rental(env: &Env, car_id: u32) -> bool {
+    has_persistent(env, &DataKey::Rental(car_id))
 }
 
-pub(crate) fn write_rental(env: &Env, renter: &Address, car_owner: &Address, rental: &Rental) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Rental(renter.clone(), car_owner.clone()), rental);
+pub(crate) fn write_rental(env: &Env, car_id: u32, rental: &Rental) {
+    write_persistent(env, &DataKey::Rental(car_id), rental);
+}
+
+pub(crate) fn read_rental(env: &Env, car_id: u32) -> Result<Rental, Error> {
+    read_persistent(env, &DataKey::Rental(car_id))
+        .ok_or(Error::RentalNotFound)
 }
 
-pub(crate) fn read_rental(
-    env: &Env,
-    renter: &Address,
-    car_owner: &Address,
-) -> Result<Rental, Error> {
+pub(crate) fn read_next_rental_id(env: &Env) -> u32 {
     env.storage()
         .instance()
-        .get(&DataKey::Rental(renter.clone(), car_owner.clone()))
-        .ok_or(Error::RentalNotFound)
+        .get(&DataKey::NextRentalId)
+        .unwrap_or(0)
+}
+
+pub(crate) fn write_next_rental_id(env: &Env, next_rental_id: u32) {
+    write_instance(env, &DataKey::NextRentalId, &next_rental_id);
 }
 
-pub(crate) fn remove_rental(env: &Env, renter: &Address, car_owner: &Address) {
+pub(crate) fn remove_rental(env: &Env, car_id: u32) {
+    remove_persistent(env, &DataKey::Rental(car_id));
+}
+
+pub(crate) fn read_reclaim_grace_period(env: &Env) -> u64 {
     env.storage()
         .instance()
-        .remove(&DataKey::Rental(renter.clone(), car_owner.clone()));
+        .get(&DataKey::ReclaimGracePeriod)
+        .unwrap_or(DEFAULT_RECLAIM_GRACE_PERIOD)
+}
+
+pub(crate) fn write_reclaim_grace_period(env: &Env, seconds: u64) {
+    write_instance(env, &DataKey::ReclaimGracePeriod, &seconds);
 }
@@ -0,0 +1,54 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::types::errors::Error;
+use crate::storage::{structs::rental_record::RentalRecord, types::storage::DataKey};
+use crate::storage::ttl::{read_persistent, write_persistent};
+
+pub(crate) fn read_rental_record(env: &Env, rental_id: u32) -> Result<RentalRecord, Error> {
+    read_persistent(env, &DataKey::RentalRecord(rental_id))
+        .ok_or(Error::RentalNotFound)
+}
+
+pub(crate) fn write_rental_record(env: &Env, record: &RentalRecord) {
+    write_persistent(env, &DataKey::RentalRecord(record.rental_id), record);
+}
+
+pub(crate) fn read_renter_rental_count(env: &Env, renter: &Address) -> u32 {
+    read_persistent(env, &DataKey::RenterRentalCount(renter.clone()))
+        .unwrap_or(0)
+}
+
+pub(crate) fn read_renter_rental(env: &Env, renter: &Address, index: u32) -> Result<u32, Error> {
+    read_persistent(env, &DataKey::RenterRental(renter.clone(), index))
+        .ok_or(Error::RentalNotFound)
+}
+
+/// Appends a rental id to the renter's history, which is indexed from 0 in rental order.
+pub(crate) fn push_renter_rental(env: &Env, renter: &Address, rental_id: u32) -> Result<(), Error> {
+    let index = read_renter_rental_count(env, renter);
+    let count = index.checked_add(1).ok_or(Error::OverflowError)?;
+
+    write_persistent(env, &DataKey::RenterRental(renter.clone(), index), &rental_id);
+    write_persistent(env, &DataKey::RenterRentalCount(renter.clone()), &count);
+    Ok(())
+}
+
+pub(crate) fn read_car_rental_count(env: &Env, car_id: u32) -> u32 {
+    read_persistent(env, &DataKey::CarRentalCount(car_id))
+        .unwrap_or(0)
+}
+
+pub(crate) fn read_car_rental(env: &Env, car_id: u32, index: u32) -> Result<u32, Error> {
+    read_persistent(env, &DataKey::CarRental(car_id, index))
+        .ok_or(Error::RentalNotFound)
+}
+
+/// Appends a rental id to the car's history, which is indexed from 0 in rental order.
+pub(crate) fn push_car_rental(env: &Env, car_id: u32, rental_id: u32) -> Result<(), Error> {
+    let index = read_car_rental_count(env, car_id);
+    let count = index.checked_add(1).ok_or(Error::OverflowError)?;
+
+    write_persistent(env, &DataKey::CarRental(car_id, index), &rental_id);
+    write_persistent(env, &DataKey::CarRentalCount(car_id), &count);
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::{Address, Env};
+
+use crate::storage::ttl::{read_persistent, remove_persistent, write_persistent};
+use crate::storage::types::{role::Role, storage::DataKey};
+
+pub(crate) fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    read_persistent(env, &DataKey::Role(role, account.clone()))
+        .unwrap_or(false)
+}
+
+pub(crate) fn write_role(env: &Env, role: Role, account: &Address) {
+    write_persistent(env, &DataKey::Role(role, account.clone()), &true);
+}
+
+pub(crate) fn remove_role(env: &Env, role: Role, account: &Address) {
+    remove_persistent(env, &DataKey::Role(role, account.clone()));
+}
@@ -0,0 +1,17 @@
+use soroban_sdk::Env;
+
+use crate::constants::LEGACY_SCHEMA_VERSION;
+use crate::storage::types::storage::DataKey;
+use crate::storage::ttl::write_instance;
+
+/// Deployments that predate schema versioning never stored a version and read as the legacy one.
+pub(crate) fn read_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(LEGACY_SCHEMA_VERSION)
+}
+
+pub(crate) fn write_schema_version(env: &Env, version: u32) {
+    write_instance(env, &DataKey::SchemaVersion, &version);
+}
@@ -0,0 +1,18 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::booking_status::BookingStatus;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct Booking {
+    pub booking_id: u32,
+    pub car_id: u32,
+    pub renter: Address,
+    pub start_ts: u64,
+    pub end_ts: u64,
+    pub total_days_to_rent: u32,
+    pub amount: i128,
+    pub commission: i128,
+    pub deposit: i128,
+    pub status: BookingStatus,
+}
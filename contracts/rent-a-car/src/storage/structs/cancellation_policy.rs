@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+/// Cancelling at least `full_refund_window` seconds before the start refunds everything;
+/// later cancellations refund `partial_refund_bps` of the price, and none once the start has passed.
+/// The schedule only applies while the car can be delivered; otherwise everything is refunded.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CancellationPolicy {
+    pub full_refund_window: u64,
+    pub partial_refund_bps: u32,
+}
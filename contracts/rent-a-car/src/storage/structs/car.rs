@@ -1,11 +1,12 @@
-﻿use soroban_sdk::{contracttype};
+﻿use soroban_sdk::{contracttype, Address};
 
 use crate::storage::types::car_status::CarStatus;
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Car {
+    pub owner: Address,
     pub price_per_day: i128,
     pub car_status: CarStatus,
-    pub available_to_withdraw: i128,
-}
\ No newline at end of file
+    pub deposit_amount: i128,
+}
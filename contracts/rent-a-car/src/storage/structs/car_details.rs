@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::structs::{car::Car, car_metadata::CarMetadata};
+
+#[derive(Clone)]
+#[contracttype]
+pub struct CarDetails {
+    pub car_id: u32,
+    pub car: Car,
+    pub metadata: CarMetadata,
+}
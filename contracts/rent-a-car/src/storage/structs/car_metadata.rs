@@ -0,0 +1,12 @@
+use soroban_sdk::{contracttype, BytesN, String};
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CarMetadata {
+    pub make: String,
+    pub model: String,
+    pub year: u32,
+    pub seats: u32,
+    pub plate_hash: BytesN<32>,
+    pub image_uri: String,
+}
@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+/// A car's amounts in a payment token other than the default one.
+/// `late_fee` replaces the late-fee policy's `fee` for rentals paid in that token.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CarTokenPrice {
+    pub price_per_day: i128,
+    pub deposit_amount: i128,
+    pub late_fee: i128,
+}
@@ -0,0 +1,14 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::types::commission_mode::CommissionMode;
+
+/// `min_fee` and `max_fee` only apply in `BasisPoints` mode; a `max_fee` of 0 means uncapped.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct CommissionConfig {
+    pub mode: CommissionMode,
+    pub flat_fee: i128,
+    pub bps: u32,
+    pub min_fee: i128,
+    pub max_fee: i128,
+}
@@ -0,0 +1,15 @@
+use soroban_sdk::{contracttype, Address, BytesN};
+
+use crate::storage::types::claim_status::ClaimStatus;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct DamageClaim {
+    pub car_id: u32,
+    pub owner: Address,
+    pub renter: Address,
+    pub amount: i128,
+    pub evidence_hash: BytesN<32>,
+    pub status: ClaimStatus,
+    pub owner_award: i128,
+}
@@ -0,0 +1,13 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::deposit_status::DepositStatus;
+
+#[derive(Clone)]
+#[contracttype]
+pub struct Deposit {
+    pub car_id: u32,
+    pub renter: Address,
+    pub amount: i128,
+    pub status: DepositStatus,
+    pub release_ts: u64,
+}
@@ -0,0 +1,8 @@
+use soroban_sdk::contracttype;
+
+/// Unused full days are refunded on early return, but never below `min_charged_days`.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct EarlyReturnPolicy {
+    pub min_charged_days: u32,
+}
@@ -0,0 +1,16 @@
+use soroban_sdk::contracttype;
+
+/// Two-sided handover of a rental. Readings are taken by the owner; return readings stay
+/// empty when the return was settled by timeout instead of confirmed.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct Handoff {
+    pub car_id: u32,
+    pub handed_over: bool,
+    pub handed_over_ts: u64,
+    pub pickup_odometer: u64,
+    pub pickup_fuel_level: u32,
+    pub returned_ts: u64,
+    pub return_odometer: Option<u64>,
+    pub return_fuel_level: Option<u32>,
+}
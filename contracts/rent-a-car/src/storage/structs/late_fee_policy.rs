@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+use crate::storage::types::late_fee_unit::LateFeeUnit;
+
+/// `fee` is charged for every started `unit` a car is kept past its due time plus `grace_period` seconds.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct LateFeePolicy {
+    pub fee: i128,
+    pub unit: LateFeeUnit,
+    pub grace_period: u64,
+}
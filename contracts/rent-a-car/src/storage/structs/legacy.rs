@@ -0,0 +1,29 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::car_status::CarStatus;
+
+/// Car layout of the first release, stored in instance storage under `Car(owner)`, one car per
+/// owner. The owner's earnings were kept on the car.
+#[derive(Clone)]
+#[contracttype]
+pub struct OwnerKeyedCar {
+    pub price_per_day: i128,
+    pub car_status: CarStatus,
+    pub available_to_withdraw: i128,
+}
+
+/// Rental layout of the first release, stored in instance storage under `Rental(renter, owner)`.
+#[derive(Clone)]
+#[contracttype]
+pub struct OwnerKeyedRental {
+    pub total_days_to_rent: u32,
+    pub amount: i128,
+}
+
+/// Points `migrate_legacy_cars` at a first-release car: its owner, and its renter while it is rented.
+#[derive(Clone)]
+#[contracttype]
+pub struct LegacyCarRef {
+    pub owner: Address,
+    pub renter: Option<Address>,
+}
@@ -1,2 +1,17 @@
-﻿pub mod car;
-pub mod rental;
\ No newline at end of file
+﻿pub mod booking;
+pub mod cancellation_policy;
+pub mod car;
+pub mod car_details;
+pub mod car_metadata;
+pub mod car_token_price;
+pub mod commission;
+pub mod damage_claim;
+pub mod deposit;
+pub mod early_return_policy;
+pub mod handoff;
+pub mod late_fee_policy;
+pub mod legacy;
+pub mod pending_admin;
+pub mod rental;
+pub mod rental_record;
+pub mod rental_quote;
\ No newline at end of file
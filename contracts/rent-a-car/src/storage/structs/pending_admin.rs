@@ -0,0 +1,9 @@
+use soroban_sdk::{contracttype, Address};
+
+/// Admin handover waiting for the proposed address to accept it before `expires_ts`.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct PendingAdmin {
+    pub admin: Address,
+    pub expires_ts: u64,
+}
@@ -1,8 +1,13 @@
-﻿use soroban_sdk::{contracttype};
+﻿use soroban_sdk::{contracttype, Address};
 
 #[derive(Clone)]
 #[contracttype]
 pub struct Rental {
+    pub rental_id: u32,
+    pub renter: Address,
     pub total_days_to_rent: u32,
     pub amount: i128,
-}
\ No newline at end of file
+    pub deposit: i128,
+    pub start_ts: u64,
+    pub due_ts: u64,
+}
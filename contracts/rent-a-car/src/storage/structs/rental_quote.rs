@@ -0,0 +1,10 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct RentalQuote {
+    pub base_price: i128,
+    pub commission: i128,
+    pub total: i128,
+    pub deposit: i128,
+}
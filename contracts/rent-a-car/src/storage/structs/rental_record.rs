@@ -0,0 +1,24 @@
+use soroban_sdk::{contracttype, Address};
+
+use crate::storage::types::rental_outcome::RentalOutcome;
+
+/// Permanent record of a rental, kept after the car is back for support and accounting.
+/// `returned_ts` is 0 while the rental is still active.
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+pub struct RentalRecord {
+    pub rental_id: u32,
+    pub car_id: u32,
+    pub renter: Address,
+    pub owner: Address,
+    pub total_days_to_rent: u32,
+    pub amount: i128,
+    pub commission: i128,
+    pub deposit: i128,
+    pub start_ts: u64,
+    pub due_ts: u64,
+    pub returned_ts: u64,
+    pub refund: i128,
+    pub late_fee: i128,
+    pub outcome: RentalOutcome,
+}
@@ -1,7 +1,11 @@
-﻿use soroban_sdk::{Address, Env};
+﻿use soroban_sdk::{vec, Address, Env, Vec};
+use crate::storage::structs::car_token_price::CarTokenPrice;
+use crate::storage::ttl::{read_persistent, write_instance, write_persistent};
 use crate::storage::types::errors::Error;
 use crate::storage::types::storage::DataKey;
 
+/// The token set in the constructor. It is the first allowed token and the one accepted by cars,
+/// rentals and bookings that never chose another.
 pub(crate) fn read_token(env: &Env) -> Result<Address, Error> {
     env.storage()
         .instance()
@@ -10,7 +14,61 @@ pub(crate) fn read_token(env: &Env) -> Result<Address, Error> {
 }
 
 pub(crate) fn write_token(env: &Env, token: &Address) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Token, &token);
-}
\ No newline at end of file
+    write_instance(env, &DataKey::Token, &token);
+}
+
+pub(crate) fn read_allowed_tokens(env: &Env) -> Result<Vec<Address>, Error> {
+    match env.storage().instance().get(&DataKey::AllowedTokens) {
+        Some(tokens) => Ok(tokens),
+        None => Ok(vec![env, read_token(env)?]),
+    }
+}
+
+pub(crate) fn write_allowed_tokens(env: &Env, tokens: &Vec<Address>) {
+    write_instance(env, &DataKey::AllowedTokens, tokens);
+}
+
+pub(crate) fn is_token_allowed(env: &Env, token: &Address) -> Result<bool, Error> {
+    Ok(read_allowed_tokens(env)?.contains(token))
+}
+
+pub(crate) fn read_car_tokens(env: &Env, car_id: u32) -> Result<Vec<Address>, Error> {
+    match read_persistent(env, &DataKey::CarTokens(car_id)) {
+        Some(tokens) => Ok(tokens),
+        None => Ok(vec![env, read_token(env)?]),
+    }
+}
+
+pub(crate) fn write_car_tokens(env: &Env, car_id: u32, tokens: &Vec<Address>) {
+    write_persistent(env, &DataKey::CarTokens(car_id), tokens);
+}
+
+pub(crate) fn read_car_token_price(env: &Env, car_id: u32, token: &Address) -> Option<CarTokenPrice> {
+    read_persistent(env, &DataKey::CarTokenPrice(car_id, token.clone()))
+}
+
+pub(crate) fn write_car_token_price(env: &Env, car_id: u32, token: &Address, price: &CarTokenPrice) {
+    write_persistent(env, &DataKey::CarTokenPrice(car_id, token.clone()), price);
+}
+
+pub(crate) fn read_rental_token(env: &Env, rental_id: u32) -> Result<Address, Error> {
+    match read_persistent(env, &DataKey::RentalToken(rental_id)) {
+        Some(token) => Ok(token),
+        None => read_token(env),
+    }
+}
+
+pub(crate) fn write_rental_token(env: &Env, rental_id: u32, token: &Address) {
+    write_persistent(env, &DataKey::RentalToken(rental_id), token);
+}
+
+pub(crate) fn read_booking_token(env: &Env, booking_id: u32) -> Result<Address, Error> {
+    match read_persistent(env, &DataKey::BookingToken(booking_id)) {
+        Some(token) => Ok(token),
+        None => read_token(env),
+    }
+}
+
+pub(crate) fn write_booking_token(env: &Env, booking_id: u32, token: &Address) {
+    write_persistent(env, &DataKey::BookingToken(booking_id), token);
+}
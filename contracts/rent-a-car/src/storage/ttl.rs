@@ -0,0 +1,51 @@
+use soroban_sdk::{Env, IntoVal, TryFromVal, Val};
+
+use crate::constants::{INSTANCE_BUMP_AMOUNT, INSTANCE_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD};
+use crate::storage::types::storage::DataKey;
+
+/// Tops up the TTL of an existing persistent entry once it falls below the threshold.
+pub(crate) fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_LIFETIME_THRESHOLD, PERSISTENT_BUMP_AMOUNT);
+}
+
+/// Tops up the TTL of the contract instance, which holds the admin, the token, the counters and the
+/// settings. Every persistent read or write and every instance write calls it, so any use of the
+/// contract keeps it alive.
+pub(crate) fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(INSTANCE_LIFETIME_THRESHOLD, INSTANCE_BUMP_AMOUNT);
+}
+
+pub(crate) fn write_instance<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().instance().set(key, value);
+    extend_instance_ttl(env);
+}
+
+pub(crate) fn has_persistent(env: &Env, key: &DataKey) -> bool {
+    env.storage().persistent().has(key)
+}
+
+/// Reads a persistent entry, extending its TTL when it exists so entries in use are never archived.
+pub(crate) fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+
+    if value.is_some() {
+        extend_persistent_ttl(env, key);
+        extend_instance_ttl(env);
+    }
+
+    value
+}
+
+pub(crate) fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    extend_persistent_ttl(env, key);
+    extend_instance_ttl(env);
+}
+
+pub(crate) fn remove_persistent(env: &Env, key: &DataKey) {
+    env.storage().persistent().remove(key);
+}
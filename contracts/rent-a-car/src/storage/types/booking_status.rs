@@ -0,0 +1,11 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum BookingStatus {
+    Reserved,
+    Active,
+    Cancelled,
+    Expired,
+}
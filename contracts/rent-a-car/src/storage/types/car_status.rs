@@ -7,4 +7,7 @@ pub enum CarStatus {
     Available,
     Rented,
     Maintenance,
+    Delisted,
+    AwaitingPickup,
+    AwaitingReturnConfirmation,
 }
\ No newline at end of file
@@ -0,0 +1,12 @@
+use soroban_sdk::contracttype;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum DepositStatus {
+    Held,
+    InInspection,
+    Released,
+    Claimed,
+    Settled,
+}
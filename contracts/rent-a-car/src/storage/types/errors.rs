@@ -20,4 +20,38 @@ pub enum Error {
     TokenNotFound = 14,
     AdminNotFound = 15,
     OverflowError = 16,
+    InsufficientPayment = 17,
+    InvalidCommission = 18,
+    Unauthorized = 19,
+    DepositNotFound = 20,
+    DepositNotReleasable = 21,
+    ArbiterNotFound = 23,
+    DamageClaimNotFound = 24,
+    DamageClaimAlreadyFiled = 25,
+    InspectionWindowClosed = 26,
+    ClaimExceedsDeposit = 27,
+    InvalidClaimStatus = 28,
+    CarUnderMaintenance = 29,
+    CarNotUnderMaintenance = 30,
+    CarDelisted = 31,
+    CarNotDelisted = 32,
+    InvalidCarMetadata = 33,
+    InvalidBookingWindow = 34,
+    BookingOverlap = 35,
+    BookingNotFound = 36,
+    BookingExpired = 38,
+    InvalidBookingStatus = 39,
+    CarHasBookings = 40,
+    TooManyBookings = 41,
+    InvalidRefundPolicy = 42,
+    RentalOverdue = 43,
+    InvalidReading = 45,
+    HandoffNotFound = 46,
+    CarNotHandedOver = 47,
+    InvalidHandoffStatus = 48,
+    TimeoutNotReached = 49,
+    AdminProposalNotFound = 50,
+    ContractPaused = 51,
+    TokenNotAllowed = 52,
+    InvalidArbiter = 53,
 }
\ No newline at end of file
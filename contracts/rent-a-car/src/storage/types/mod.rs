@@ -1,3 +1,11 @@
-﻿pub mod car_status;
+﻿pub mod booking_status;
+pub mod car_status;
+pub mod claim_status;
+pub mod commission_mode;
+pub mod deposit_status;
+pub mod late_fee_unit;
+pub mod rental_outcome;
 pub mod storage;
-pub mod errors;
\ No newline at end of file
+pub mod errors;
+pub mod return_timing;
+pub mod role;
\ No newline at end of file
@@ -0,0 +1,25 @@
+use soroban_sdk::contracttype;
+
+use crate::constants::SECONDS_PER_DAY;
+
+#[derive(Clone, PartialEq, Debug)]
+#[contracttype]
+#[repr(u32)]
+pub enum ReturnTiming {
+    Early,
+    OnTime,
+    Late,
+}
+
+impl ReturnTiming {
+    /// A return is early when at least one full rental day is left before `due_ts`.
+    pub fn from_timestamps(returned_ts: u64, due_ts: u64) -> Self {
+        if returned_ts > due_ts {
+            ReturnTiming::Late
+        } else if due_ts - returned_ts >= SECONDS_PER_DAY {
+            ReturnTiming::Early
+        } else {
+            ReturnTiming::OnTime
+        }
+    }
+}
@@ -1,12 +1,62 @@
 ﻿use soroban_sdk::{contracttype, Address};
 
+use crate::storage::types::role::Role;
+
 #[derive(Clone)]
 #[contracttype]
 pub enum DataKey {
     Admin,
+    Arbiter,
     Token,
-    ContractBalance,
+    ContractBalance(Address),
+    DepositBalance(Address),
+    InspectionWindow,
     AdminCommission,
+    CommissionConfig,
+    AdminAvailableToWithdraw(Address),
+    NextCarId,
+    Car(u32),
+    CarMetadata(u32),
+    OwnerAvailableToWithdraw(Address, Address),
+    NextRentalId,
+    Rental(u32),
+    Deposit(u32),
+    DamageClaim(u32),
+    NextBookingId,
+    Booking(u32),
+    CarBookings(u32),
+    CancellationPolicy,
+    EarlyReturnPolicy(u32),
+    LateFeePolicy(u32),
+    LateFeePlatformShare,
+    ReclaimGracePeriod,
+    HandoffRequired(u32),
+    Handoff(u32),
+    RentalRecord(u32),
+    RenterRentalCount(Address),
+    RenterRental(Address, u32),
+    CarRentalCount(u32),
+    CarRental(u32, u32),
+    PendingAdmin,
+    Role(Role, Address),
+    Paused,
+    SchemaVersion,
+    AllowedTokens,
+    CarTokens(u32),
+    RentalToken(u32),
+    BookingToken(u32),
+    OwnerCarCount(Address),
+    Treasury,
+    CarTokenPrice(u32, Address),
+    TokenCommission(Address),
+}
+
+/// Keys of the first release: single-token balances, and cars and rentals keyed by owner address.
+/// Only read by `migrate` and `migrate_legacy_cars`.
+#[derive(Clone)]
+#[contracttype]
+pub enum LegacyDataKey {
+    ContractBalance,
     AdminAvailableToWithdraw,
     Car(Address),
     Rental(Address, Address),
@@ -1,4 +1,4 @@
-﻿use crate::tests::config::utils::get_contract_events;
+﻿use crate::tests::config::utils::{car_metadata, get_contract_events};
 use crate::{storage::{car::read_car, types::car_status::CarStatus}, tests::config::contract::ContractTest};
 use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
@@ -10,22 +10,23 @@ pub fn test_add_car_successfully() {
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
+    let metadata = car_metadata(&env);
 
-    contract
+    let car_id = contract
         .mock_auths(&[MockAuth {
             address: &admin,
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (admin.clone(), owner.clone(), price_per_day, metadata.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .add_car( &owner, &price_per_day);
+        .add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     let contract_events = get_contract_events(&env, &contract.address);
 
     let stored_car = env.as_contract(&contract.address, || {
-        read_car(&env, &owner)
+        read_car(&env, car_id)
     }).unwrap();
 
     assert_eq!(stored_car.price_per_day, price_per_day);
@@ -42,7 +43,7 @@ pub fn test_add_car_successfully() {
                     *Symbol::new(&env, "car_added").as_val(),
                     owner.clone().into_val(&env),
                 ],
-                price_per_day.into_val(&env)
+                (car_id, price_per_day, metadata).into_val(&env)
             )
         ]
     );
@@ -51,36 +52,47 @@ pub fn test_add_car_successfully() {
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_add_car_with_zero_price_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
     let price_per_day = 0_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_add_car_with_negative_price_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
     let price_per_day = -100_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #10)")]
-pub fn test_add_car_already_exists_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+pub fn test_add_multiple_cars_for_same_owner() {
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     let owner = Address::generate(&env);
-    let price_per_day = 1500_i128;
+    let first_price_per_day = 1500_i128;
+    let second_price_per_day = 2500_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
-    contract.add_car(&owner, &price_per_day);
+    let first_car_id = contract.add_car(&admin, &owner, &first_price_per_day, &car_metadata(&env));
+    let second_car_id = contract.add_car(&admin, &owner, &second_price_per_day, &car_metadata(&env));
+
+    assert_eq!(first_car_id, 0);
+    assert_eq!(second_car_id, 1);
+
+    let first_car = env.as_contract(&contract.address, || read_car(&env, first_car_id)).unwrap();
+    let second_car = env.as_contract(&contract.address, || read_car(&env, second_car_id)).unwrap();
+
+    assert_eq!(first_car.owner, owner);
+    assert_eq!(first_car.price_per_day, first_price_per_day);
+    assert_eq!(second_car.owner, owner);
+    assert_eq!(second_car.price_per_day, second_price_per_day);
 }
\ No newline at end of file
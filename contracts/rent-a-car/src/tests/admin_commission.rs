@@ -1,6 +1,7 @@
 use soroban_sdk::{testutils::Address as _, Address, IntoVal};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
-use crate::{storage::admin::read_admin_commission, tests::config::contract::ContractTest};
+use crate::{storage::{admin::read_admin_commission, types::{commission_mode::CommissionMode, storage::DataKey}}, tests::config::contract::ContractTest};
+use crate::tests::config::utils::car_metadata;
 
 #[test]
 pub fn test_set_admin_commission_successfully() {
@@ -14,17 +15,18 @@ pub fn test_set_admin_commission_successfully() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "set_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (admin.clone(), commission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .set_admin_commission(&commission);
+        .set_admin_commission(&admin, &commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
         read_admin_commission(&env)
     });
 
-    assert_eq!(stored_commission, commission);
+    assert_eq!(stored_commission.mode, CommissionMode::Flat);
+    assert_eq!(stored_commission.flat_fee, commission);
 }
 
 #[test]
@@ -39,34 +41,35 @@ pub fn test_set_admin_commission_zero() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "set_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (admin.clone(), commission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .set_admin_commission(&commission);
+        .set_admin_commission(&admin, &commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
         read_admin_commission(&env)
     });
 
-    assert_eq!(stored_commission, commission);
+    assert_eq!(stored_commission.mode, CommissionMode::Flat);
+    assert_eq!(stored_commission.flat_fee, commission);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_set_admin_commission_with_negative_value_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
     let commission = -100_i128;
 
     env.mock_all_auths();
 
-    contract.set_admin_commission(&commission);
+    contract.set_admin_commission(&admin, &commission);
 }
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_set_admin_commission() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
     let commission = 100_i128;
@@ -77,34 +80,184 @@ pub fn test_unauthorized_user_cannot_set_admin_commission() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "set_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (admin.clone(), commission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .set_admin_commission(&commission);
+        .set_admin_commission(&admin, &commission);
 }
 
 #[test]
 pub fn test_update_admin_commission() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let first_commission = 100_i128;
     let second_commission = 200_i128;
 
     env.mock_all_auths();
 
-    contract.set_admin_commission(&first_commission);
+    contract.set_admin_commission(&admin, &first_commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
         read_admin_commission(&env)
     });
-    assert_eq!(stored_commission, first_commission);
+    assert_eq!(stored_commission.flat_fee, first_commission);
 
-    contract.set_admin_commission(&second_commission);
+    contract.set_admin_commission(&admin, &second_commission);
 
     let stored_commission = env.as_contract(&contract.address, || {
         read_admin_commission(&env)
     });
-    assert_eq!(stored_commission, second_commission);
+    assert_eq!(stored_commission.flat_fee, second_commission);
+}
+
+
+#[test]
+pub fn test_set_admin_commission_bps_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let bps = 750_u32;
+    let min_fee = 100_i128;
+    let max_fee = 5_000_i128;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_admin_commission_bps",
+                args: (admin.clone(), bps, min_fee, max_fee).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_admin_commission_bps(&admin, &bps, &min_fee, &max_fee);
+
+    let config = contract.get_admin_commission();
+
+    assert_eq!(config.mode, CommissionMode::BasisPoints);
+    assert_eq!(config.bps, bps);
+    assert_eq!(config.min_fee, min_fee);
+    assert_eq!(config.max_fee, max_fee);
 }
 
+#[test]
+pub fn test_bps_commission_scales_with_rental_size() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+
+    // 7.5% with no floor or cap
+    contract.set_admin_commission_bps(&admin, &750, &0, &0);
+
+    let one_day = contract.quote_rental(&car_id, &1, &token.0.address);
+    assert_eq!(one_day.commission, 750);
+    assert_eq!(one_day.total, 10_750);
+
+    let sixty_days = contract.quote_rental(&car_id, &60, &token.0.address);
+    assert_eq!(sixty_days.commission, 45_000);
+    assert_eq!(sixty_days.total, 645_000);
+}
+
+#[test]
+pub fn test_bps_commission_is_clamped_to_min_and_max_fee() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 10_000_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+
+    contract.set_admin_commission_bps(&admin, &750, &1_000, &20_000);
+
+    // 750 is below the floor
+    assert_eq!(contract.quote_rental(&car_id, &1, &token.0.address).commission, 1_000);
+    // 7_500 sits between floor and cap
+    assert_eq!(contract.quote_rental(&car_id, &10, &token.0.address).commission, 7_500);
+    // 45_000 is above the cap
+    assert_eq!(contract.quote_rental(&car_id, &60, &token.0.address).commission, 20_000);
+}
+
+#[test]
+pub fn test_rental_charges_bps_commission() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission_bps(&admin, &1_000, &0, &0);
+
+    contract.rental(&renter, &car_id, &total_days, &(amount + 450), &token_client.address);
+
+    assert_eq!(contract.get_admin_available_to_withdraw(&token_client.address), 450);
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - 450);
+}
+
+#[test]
+pub fn test_legacy_flat_commission_is_still_applied() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let legacy_commission = 300_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    // Simulate a deployment that only ever stored the flat amount
+    env.as_contract(&contract.address, || {
+        env.storage().instance().set(&DataKey::AdminCommission, &legacy_commission);
+    });
+
+    let config = contract.get_admin_commission();
+    assert_eq!(config.mode, CommissionMode::Flat);
+    assert_eq!(config.flat_fee, legacy_commission);
+
+    assert_eq!(contract.quote_rental(&car_id, &3, &token.0.address).commission, legacy_commission);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_set_admin_commission_bps_above_100_percent_fails() {
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_admin_commission_bps(&admin, &10_001, &0, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_set_admin_commission_bps_max_below_min_fails() {
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_admin_commission_bps(&admin, &750, &1_000, &500);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_set_admin_commission_bps_negative_fee_fails() {
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_admin_commission_bps(&admin, &750, &-1, &0);
+}
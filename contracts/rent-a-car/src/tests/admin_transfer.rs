@@ -0,0 +1,137 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}};
+
+const WEEK: u64 = 7 * 86_400;
+
+#[test]
+pub fn test_admin_is_transferred_once_accepted() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let new_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.propose_admin(&new_admin);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events.slice(contract_events.len() - 1..),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "admin_proposed").as_val(),
+                    admin.clone().into_val(&env),
+                ],
+                (new_admin.clone(), WEEK).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_admin(), admin);
+    assert_eq!(contract.get_pending_admin().unwrap().admin, new_admin);
+
+    contract.accept_admin();
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "admin_transferred").as_val()],
+                (admin.clone(), new_admin.clone()).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_admin(), new_admin);
+    assert_eq!(contract.get_pending_admin(), None);
+}
+
+#[test]
+pub fn test_new_proposal_replaces_the_pending_one() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let first_admin = Address::generate(&env);
+    let second_admin = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.propose_admin(&first_admin);
+    contract.propose_admin(&second_admin);
+
+    assert_eq!(contract.get_pending_admin().unwrap().admin, second_admin);
+}
+
+#[test]
+pub fn test_renounced_admin_leaves_owners_in_control() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1000_i128, &car_metadata(&env));
+    contract.propose_admin(&Address::generate(&env));
+    contract.renounce_admin();
+
+    assert_eq!(contract.get_pending_admin(), None);
+    assert!(contract.try_get_admin().is_err());
+    assert!(contract.try_set_admin_commission(&admin, &100).is_err());
+    assert!(contract.try_update_car(&admin, &car_id, &2000).is_err());
+
+    contract.update_car(&owner, &car_id, &2000);
+    assert_eq!(contract.get_car(&car_id).car.price_per_day, 2000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_accept_expired_proposal_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.propose_admin(&Address::generate(&env));
+
+    env.ledger().set_timestamp(WEEK + 1);
+    assert_eq!(contract.get_pending_admin(), None);
+
+    contract.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #50)")]
+pub fn test_accept_without_proposal_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.accept_admin();
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #3)")]
+pub fn test_propose_token_as_admin_fails() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.propose_admin(&token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_propose_arbiter_as_admin_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let arbiter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_arbiter(&arbiter);
+    contract.propose_admin(&arbiter);
+}
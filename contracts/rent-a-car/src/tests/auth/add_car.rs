@@ -1,14 +1,15 @@
 ﻿use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
-use crate::tests::config::contract::ContractTest;
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
 
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_add_car() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
+    let metadata = car_metadata(&env);
 
     contract
         .mock_auths(&[MockAuth {
@@ -16,8 +17,8 @@ pub fn test_unauthorized_user_cannot_add_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "add_car",
-                args: (owner.clone(), price_per_day).into_val(&env),
+                args: (admin.clone(), owner.clone(), price_per_day, metadata.clone()).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).add_car(&owner, &price_per_day);
+        }]).add_car(&admin, &owner, &price_per_day, &metadata);
 }
\ No newline at end of file
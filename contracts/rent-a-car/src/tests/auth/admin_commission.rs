@@ -5,7 +5,7 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_set_admin_commission() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
     let commission = 100_i128;
@@ -16,10 +16,33 @@ pub fn test_unauthorized_user_cannot_set_admin_commission() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "set_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (admin.clone(), commission).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .set_admin_commission(&commission);
+        .set_admin_commission(&admin, &commission);
 }
 
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_admin_commission_bps() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let bps = 750_u32;
+    let min_fee = 0_i128;
+    let max_fee = 0_i128;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_admin_commission_bps",
+                args: (admin.clone(), bps, min_fee, max_fee).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_admin_commission_bps(&admin, &bps, &min_fee, &max_fee);
+}
@@ -0,0 +1,30 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_reserve_as_renter() {
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_renter = Address::generate(&env);
+    let start_ts = 86_400_u64;
+    let end_ts = 2 * 86_400_u64;
+    let amount = 1500_i128;
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &amount, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "reserve",
+                args: (renter.clone(), car_id, start_ts, end_ts, amount, token.0.address.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .reserve(&renter, &car_id, &start_ts, &end_ts, &amount, &token.0.address);
+}
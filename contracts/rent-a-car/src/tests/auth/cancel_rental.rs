@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_cancel_as_renter() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &86_400, &(2 * 86_400), &1500_i128, &token.0.address);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "cancel_rental",
+                args: (renter.clone(), booking_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .cancel_rental(&renter, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_cancellation_policy() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let full_refund_window = 86_400_u64;
+    let partial_refund_bps = 5_000_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_cancellation_policy",
+                args: (full_refund_window, partial_refund_bps).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_cancellation_policy(&full_refund_window, &partial_refund_bps);
+}
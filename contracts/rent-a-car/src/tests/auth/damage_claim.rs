@@ -0,0 +1,48 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::contract::ContractTest;
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_arbiter() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let arbiter = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_arbiter",
+                args: (arbiter.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_arbiter(&arbiter);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_admin_cannot_resolve_dispute_for_arbiter() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let arbiter = Address::generate(&env);
+    let rental_id = 0_u32;
+    let owner_amount = 500_i128;
+
+    env.mock_all_auths();
+    contract.set_arbiter(&arbiter);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "resolve_dispute",
+                args: (rental_id, owner_amount).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .resolve_dispute(&rental_id, &owner_amount);
+}
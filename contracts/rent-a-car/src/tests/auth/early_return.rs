@@ -0,0 +1,27 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::{storage::structs::early_return_policy::EarlyReturnPolicy, tests::config::{contract::ContractTest, utils::car_metadata}};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_early_return_policy_as_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let policy = Some(EarlyReturnPolicy { min_charged_days: 1 });
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_early_return_policy",
+                args: (owner.clone(), car_id, policy.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_early_return_policy(&owner, &car_id, &policy);
+}
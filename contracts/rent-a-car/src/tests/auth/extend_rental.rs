@@ -0,0 +1,31 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_extend_rental_as_renter() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_renter = Address::generate(&env);
+    let extra_days = 1_u32;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "extend_rental",
+                args: (renter.clone(), car_id, extra_days).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .extend_rental(&renter, &car_id, &extra_days);
+}
@@ -0,0 +1,53 @@
+use soroban_sdk::{testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_force_return_as_owner() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+
+    env.ledger().set_timestamp(10 * 86_400);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "force_return",
+                args: (owner.clone(), car_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .force_return(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_reclaim_grace_period() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let seconds = 3_600_u64;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_reclaim_grace_period",
+                args: (seconds,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_reclaim_grace_period(&seconds);
+}
@@ -0,0 +1,93 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_mark_handed_over_as_owner() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "mark_handed_over",
+                args: (owner.clone(), car_id, 0_u64, 50_u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .mark_handed_over(&owner, &car_id, &0, &50);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_confirm_pickup_as_renter() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_renter,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "confirm_pickup",
+                args: (renter.clone(), car_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .confirm_pickup(&renter, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_confirm_return_as_owner() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+    contract.confirm_pickup(&renter, &car_id);
+    contract.return_car(&renter, &car_id);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "confirm_return",
+                args: (owner.clone(), car_id, 10_u64, 40_u32).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .confirm_return(&owner, &car_id, &10, &40);
+}
@@ -0,0 +1,51 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::{
+    storage::{structs::late_fee_policy::LateFeePolicy, types::late_fee_unit::LateFeeUnit},
+    tests::config::{contract::ContractTest, utils::car_metadata},
+};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_late_fee_policy_as_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let policy = Some(LateFeePolicy { fee: 50, unit: LateFeeUnit::Hour, grace_period: 0 });
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_late_fee_policy",
+                args: (owner.clone(), car_id, policy.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_late_fee_policy(&owner, &car_id, &policy);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_late_fee_platform_share() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let bps = 1_000_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_late_fee_platform_share",
+                args: (bps,).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_late_fee_platform_share(&bps);
+}
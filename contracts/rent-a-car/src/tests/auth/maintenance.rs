@@ -0,0 +1,28 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, BytesN};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_start_maintenance_as_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let reason_code = 1_u32;
+    let document_hash: Option<BytesN<32>> = None;
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "start_maintenance",
+                args: (owner.clone(), car_id, reason_code, document_hash.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .start_maintenance(&owner, &car_id, &reason_code, &document_hash);
+}
@@ -1,7 +1,24 @@
 ﻿mod add_car;
+mod booking;
+mod cancel_rental;
+mod damage_claim;
+mod deposit;
+mod early_return;
+mod extend_rental;
+mod force_return;
+mod handoff;
+mod late_fee;
+mod maintenance;
+mod multi_token;
+mod pause;
 mod payout_owner;
 mod remove_car;
 mod rental;
+mod role;
+mod storage;
+mod update_car;
+mod upgrade;
 mod admin_commission;
+mod admin_transfer;
 mod withdraw_admin_commission;
 mod return_car;
\ No newline at end of file
@@ -0,0 +1,123 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, vec, IntoVal, Address};
+use crate::{
+    storage::{structs::{car_token_price::CarTokenPrice, commission::CommissionConfig}, types::commission_mode::CommissionMode},
+    tests::config::{contract::ContractTest, utils::{car_metadata, create_token_contract}},
+};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_allow_token() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "allow_token",
+                args: (second_token.address.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .allow_token(&second_token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_disallow_token() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "disallow_token",
+                args: (token.0.address.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .disallow_token(&token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_tokens() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let tokens = vec![&env, token.0.address.clone()];
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1000_i128, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_tokens",
+                args: (owner.clone(), car_id, tokens.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_car_tokens(&owner, &car_id, &tokens);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_car_token_price() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+    let price = CarTokenPrice { price_per_day: 1000, deposit_amount: 0, late_fee: 0 };
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1000_i128, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_car_token_price",
+                args: (owner.clone(), car_id, second_token.address.clone(), price.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_car_token_price(&owner, &car_id, &second_token.address, &price);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_token_commission() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+    let config = CommissionConfig { mode: CommissionMode::Flat, flat_fee: 100, bps: 0, min_fee: 0, max_fee: 0 };
+
+    env.mock_all_auths();
+    contract.allow_token(&second_token.address);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_token_commission",
+                args: (admin.clone(), second_token.address.clone(), config.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_token_commission(&admin, &second_token.address, &config);
+}
@@ -5,7 +5,7 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_payout_owner() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let fake_owner = Address::generate(&env);
@@ -17,8 +17,8 @@ pub fn test_unauthorized_user_cannot_payout_owner() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "payout_owner",
-                args: (owner.clone(), amount).into_val(&env),
+                args: (owner.clone(), amount, token.0.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).payout_owner(&owner, &amount);
+        }]).payout_owner(&owner, &amount, &token.0.address);
 }
\ No newline at end of file
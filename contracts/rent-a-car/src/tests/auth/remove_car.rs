@@ -4,10 +4,10 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_remove_car() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
-    let owner = Address::generate(&env);
+    let car_id = 0_u32;
 
     contract
         .mock_auths(&[MockAuth {
@@ -15,8 +15,27 @@ pub fn test_unauthorized_user_cannot_remove_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "remove_car",
-                args: (owner.clone(),).into_val(&env),
+                args: (admin.clone(), car_id).into_val(&env),
                 sub_invokes: &[],
             },
-        }]).remove_car(&owner);
-}
\ No newline at end of file
+        }]).remove_car(&admin, &car_id);
+}
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_relist_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let car_id = 0_u32;
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "relist_car",
+                args: (admin.clone(), car_id).into_val(&env),
+                sub_invokes: &[],
+            },
+        }]).relist_car(&admin, &car_id);
+}
@@ -1,11 +1,11 @@
 use soroban_sdk::{testutils::Address as _, Address, IntoVal};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
-use crate::tests::config::contract::ContractTest;
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 pub fn test_unauthorized_user_cannot_return_car() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -21,8 +21,8 @@ pub fn test_unauthorized_user_cannot_return_car() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Unauthorized user tries to return the car (will fail because no rental exists for unauthorized)
     contract
@@ -31,10 +31,10 @@ pub fn test_unauthorized_user_cannot_return_car() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "return_car",
-                args: (unauthorized.clone(), owner.clone()).into_val(&env),
+                args: (unauthorized.clone(), car_id).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .return_car(&unauthorized, &owner);
+        .return_car(&unauthorized, &car_id);
 }
 
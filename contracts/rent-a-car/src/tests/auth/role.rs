@@ -0,0 +1,96 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::{storage::types::role::Role, tests::config::{contract::ContractTest, utils::car_metadata}};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_grant_role() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "grant_role",
+                args: (fake_admin.clone(), Role::Finance).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .grant_role(&fake_admin, &Role::Finance);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_revoke_role() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fake_admin = Address::generate(&env);
+    let finance = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&finance, &Role::Finance);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_admin,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "revoke_role",
+                args: (finance.clone(), Role::Finance).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .revoke_role(&finance, &Role::Finance);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_add_car_as_fleet_manager() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+    let fake_manager = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let metadata = car_metadata(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_manager,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "add_car",
+                args: (fleet_manager.clone(), owner.clone(), 1000_i128, metadata.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .add_car(&fleet_manager, &owner, &1000, &metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_set_treasury() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let finance = Address::generate(&env);
+    let treasury = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&finance, &Role::Finance);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &finance,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "set_treasury",
+                args: (treasury.clone(),).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .set_treasury(&treasury);
+}
@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_update_car_as_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let price_per_day = 2000_i128;
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "update_car",
+                args: (owner.clone(), car_id, price_per_day).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .update_car(&owner, &car_id, &price_per_day);
+}
+
+#[test]
+#[should_panic(expected = "Error(Auth, InvalidAction)")]
+pub fn test_unauthorized_user_cannot_update_car_metadata_as_owner() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let fake_owner = Address::generate(&env);
+    let metadata = car_metadata(&env);
+
+    env.mock_all_auths();
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &metadata);
+
+    contract
+        .mock_auths(&[MockAuth {
+            address: &fake_owner,
+            invoke: &MockAuthInvoke {
+                contract: &contract.address.clone(),
+                fn_name: "update_car_metadata",
+                args: (owner.clone(), car_id, metadata.clone()).into_val(&env),
+                sub_invokes: &[],
+            },
+        }])
+        .update_car_metadata(&owner, &car_id, &metadata);
+}
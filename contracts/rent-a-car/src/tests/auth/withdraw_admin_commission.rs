@@ -5,7 +5,7 @@ use crate::tests::config::contract::ContractTest;
 #[test]
 #[should_panic(expected = "Error(Auth, InvalidAction)")]
 pub fn test_unauthorized_user_cannot_withdraw_admin_commission() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, token, .. } = ContractTest::setup();
 
     let fake_admin = Address::generate(&env);
     let amount = 100_i128;
@@ -16,10 +16,10 @@ pub fn test_unauthorized_user_cannot_withdraw_admin_commission() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (amount,).into_val(&env),
+                args: (admin.clone(), amount, token.0.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&amount);
+        .withdraw_admin_commission(&admin, &amount, &token.0.address);
 }
 
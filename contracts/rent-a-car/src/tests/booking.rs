@@ -0,0 +1,469 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, types::{booking_status::BookingStatus, car_status::CarStatus}},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1500;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_reserve_takes_payment_and_blocks_window() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let start_ts = 10 * DAY;
+    let end_ts = 13 * DAY;
+    let amount = 3 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &amount, &token.0.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_reserved").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, start_ts, end_ts, amount).into_val(&env)
+            )
+        ]
+    );
+
+    let booking = contract.get_booking(&booking_id);
+    assert_eq!(booking.status, BookingStatus::Reserved);
+    assert_eq!(booking.total_days_to_rent, 3);
+    assert_eq!(booking.amount, amount);
+
+    // The car stays available until the reservation starts
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, amount);
+}
+
+#[test]
+pub fn test_reservation_becomes_rental_at_start() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let start_ts = 10 * DAY;
+    let end_ts = 12 * DAY;
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(start_ts);
+    let rental_id = contract.start_reservation(&booking_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "reservation_started").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, rental_id).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Rented);
+    assert_eq!(contract.get_booking(&booking_id).status, BookingStatus::Active);
+    assert_eq!(contract.get_bookings(&car_id, &0, &u64::MAX).len(), 0);
+
+    let rental = contract.get_active_rental(&car_id);
+    assert_eq!(rental.rental_id, rental_id);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.amount, amount);
+    assert_eq!(rental.start_ts, start_ts);
+    assert_eq!(rental.due_ts, end_ts);
+
+    // Owner is credited at return, as with a walk-in rental
+    contract.return_car(&renter, &car_id);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+}
+
+#[test]
+pub fn test_reservation_holds_deposit_until_return() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1000_i128;
+    let start_ts = DAY;
+    let end_ts = 2 * DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+
+    let booking_id = contract.reserve(&renter, &car_id, &start_ts, &end_ts, &(PRICE_PER_DAY + deposit), &token.0.address);
+
+    assert_eq!(contract.get_booking(&booking_id).deposit, deposit);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - PRICE_PER_DAY - deposit);
+    assert_eq!(env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address)), deposit);
+
+    env.ledger().set_timestamp(start_ts);
+    let rental_id = contract.start_reservation(&booking_id);
+
+    assert_eq!(contract.get_deposit(&rental_id).amount, deposit);
+}
+
+#[test]
+pub fn test_get_bookings_returns_overlapping_window() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let first_booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+    let second_booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(6 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    assert_eq!(contract.get_bookings(&car_id, &0, &u64::MAX).len(), 2);
+
+    let first_week = contract.get_bookings(&car_id, &0, &(3 * DAY));
+    assert_eq!(first_week.len(), 1);
+    assert_eq!(first_week.get(0).unwrap().booking_id, first_booking_id);
+
+    let later = contract.get_bookings(&car_id, &(2 * DAY), &(7 * DAY));
+    assert_eq!(later.len(), 1);
+    assert_eq!(later.get(0).unwrap().booking_id, second_booking_id);
+}
+
+#[test]
+pub fn test_adjacent_reservations_are_allowed() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+    contract.reserve(&renter, &car_id, &(2 * DAY), &(3 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    assert_eq!(contract.get_bookings(&car_id, &0, &u64::MAX).len(), 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_overlapping_reservation_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let first_renter = Address::generate(&env);
+    let second_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&first_renter, &AMOUNT_MINT);
+    token.1.mint(&second_renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.reserve(&first_renter, &car_id, &DAY, &(3 * DAY), &(2 * PRICE_PER_DAY), &token.0.address);
+
+    contract.reserve(&second_renter, &car_id, &(2 * DAY), &(4 * DAY), &(2 * PRICE_PER_DAY), &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_rental_running_into_reservation_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let first_renter = Address::generate(&env);
+    let second_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&first_renter, &AMOUNT_MINT);
+    token.1.mint(&second_renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.reserve(&first_renter, &car_id, &(2 * DAY), &(3 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.rental(&second_renter, &car_id, &3, &(3 * PRICE_PER_DAY), &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_reservation_during_active_rental_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let first_renter = Address::generate(&env);
+    let second_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&first_renter, &AMOUNT_MINT);
+    token.1.mint(&second_renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&first_renter, &car_id, &3, &(3 * PRICE_PER_DAY), &token.0.address);
+
+    contract.reserve(&second_renter, &car_id, &(2 * DAY), &(4 * DAY), &(2 * PRICE_PER_DAY), &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #34)")]
+pub fn test_reservation_in_the_past_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+    env.ledger().set_timestamp(5 * DAY);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_reservation_with_insufficient_payment_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    // A day and a half is charged as two days
+    contract.reserve(&renter, &car_id, &DAY, &(DAY + DAY + DAY / 2), &(PRICE_PER_DAY + PRICE_PER_DAY / 2), &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_reservation_amount_below_deposit_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &1000_i128);
+
+    // The deposit is part of what the renter agrees to pay
+    contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_start_reservation_before_start_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.start_reservation(&booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #38)")]
+pub fn test_start_reservation_after_end_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY);
+    contract.start_reservation(&booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")]
+pub fn test_start_reservation_twice_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.start_reservation(&booking_id);
+    contract.start_reservation(&booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #40)")]
+pub fn test_remove_car_with_reservation_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.remove_car(&admin, &car_id);
+}
+
+#[test]
+pub fn test_expire_booking_settles_unstarted_reservation() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1000_i128;
+    let price = PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &(price + deposit), &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY);
+    let refund = contract.expire_booking(&booking_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "booking_expired").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, 0_i128, deposit).into_val(&env)
+            )
+        ]
+    );
+
+    // The car was there the whole window, so the price is kept like a late cancellation
+    assert_eq!(refund, 0);
+    assert_eq!(contract.get_booking(&booking_id).status, BookingStatus::Expired);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - price);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), price);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    assert_eq!(deposit_balance, 0);
+
+    // Nothing holds the car any more
+    contract.remove_car(&admin, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_expire_booking_before_end_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.expire_booking(&booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #41)")]
+pub fn test_renter_booking_limit_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    for day in 1..5 {
+        contract.reserve(&renter, &car_id, &(day * DAY), &((day + 1) * DAY), &PRICE_PER_DAY, &token.0.address);
+    }
+}
+
+#[test]
+pub fn test_past_bookings_do_not_count_toward_limit() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    for day in 1..4 {
+        contract.reserve(&renter, &car_id, &(day * DAY), &((day + 1) * DAY), &PRICE_PER_DAY, &token.0.address);
+    }
+
+    // None of them was started or expired, but their windows are over
+    env.ledger().set_timestamp(4 * DAY);
+    contract.reserve(&renter, &car_id, &(5 * DAY), &(6 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    assert_eq!(contract.get_bookings(&car_id, &(4 * DAY), &(10 * DAY)).len(), 1);
+}
@@ -0,0 +1,234 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, structs::cancellation_policy::CancellationPolicy, types::booking_status::BookingStatus},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1500;
+const COMMISSION: i128 = 100;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_default_cancellation_policy() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    assert_eq!(
+        contract.get_cancellation_policy(),
+        CancellationPolicy { full_refund_window: DAY, partial_refund_bps: 5_000 }
+    );
+}
+
+#[test]
+pub fn test_early_cancellation_refunds_everything() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1000_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    let booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(7 * DAY), &(2 * PRICE_PER_DAY + COMMISSION + deposit), &token.0.address);
+
+    let refund = contract.cancel_rental(&renter, &booking_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(refund, 2 * PRICE_PER_DAY + COMMISSION);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "booking_cancelled").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (booking_id, refund, deposit).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT);
+    assert_eq!(contract.get_booking(&booking_id).status, BookingStatus::Cancelled);
+    assert_eq!(contract.get_bookings(&car_id, &0, &u64::MAX).len(), 0);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 0);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 0);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, 0);
+    assert_eq!(deposit_balance, 0);
+    assert_eq!(token.0.balance(&contract.address), 0);
+}
+
+#[test]
+pub fn test_late_cancellation_refunds_partially() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    contract.set_cancellation_policy(&(2 * DAY), &2_500);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(7 * DAY), &(price + COMMISSION), &token.0.address);
+
+    env.ledger().set_timestamp(4 * DAY);
+    let refund = contract.cancel_rental(&renter, &booking_id);
+
+    let refunded_price = price / 4;
+    let refunded_commission = COMMISSION / 4;
+    assert_eq!(refund, refunded_price + refunded_commission);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - price - COMMISSION + refund);
+
+    // The kept share is credited as earnings, and the contract balance still covers every pool
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), price - refunded_price);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), COMMISSION - refunded_commission);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(
+        contract_balance,
+        contract.get_owner_available_to_withdraw(&owner, &token.0.address) + contract.get_admin_available_to_withdraw(&token.0.address)
+    );
+    assert_eq!(token.0.balance(&contract.address), contract_balance);
+
+    contract.payout_owner(&owner, &(price - refunded_price), &token.0.address);
+    contract.withdraw_admin_commission(&admin, &(COMMISSION - refunded_commission), &token.0.address);
+    assert_eq!(token.0.balance(&contract.address), 0);
+}
+
+#[test]
+pub fn test_cancellation_after_start_refunds_only_deposit() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1000_i128;
+    let price = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    let booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(7 * DAY), &(price + deposit), &token.0.address);
+
+    env.ledger().set_timestamp(5 * DAY);
+    let refund = contract.cancel_rental(&renter, &booking_id);
+
+    assert_eq!(refund, 0);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - price);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), price);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, price);
+    assert_eq!(deposit_balance, 0);
+}
+
+#[test]
+pub fn test_cancelled_window_can_be_booked_again() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(6 * DAY), &PRICE_PER_DAY, &token.0.address);
+    contract.cancel_rental(&renter, &booking_id);
+
+    let second_booking_id = contract.reserve(&renter, &car_id, &(5 * DAY), &(6 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    let bookings = contract.get_bookings(&car_id, &0, &u64::MAX);
+    assert_eq!(bookings.len(), 1);
+    assert_eq!(bookings.get(0).unwrap().booking_id, second_booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #39)")]
+pub fn test_cancel_started_rental_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.start_reservation(&booking_id);
+
+    contract.cancel_rental(&renter, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_cancel_someone_elses_booking_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.cancel_rental(&stranger, &booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #42)")]
+pub fn test_set_cancellation_policy_above_full_refund_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_cancellation_policy(&DAY, &10_001);
+}
+
+#[test]
+pub fn test_cancellation_when_car_is_not_returned_refunds_everything() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let late_renter = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&late_renter, &AMOUNT_MINT);
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&late_renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(3 * DAY), &price, &token.0.address);
+
+    // The previous renter still has the car after the reservation should have started
+    env.ledger().set_timestamp(DAY + 3_600);
+    let refund = contract.cancel_rental(&renter, &booking_id);
+
+    assert_eq!(refund, price);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 0);
+}
@@ -1,4 +1,5 @@
-﻿use soroban_sdk::{token, Address, Env};
+﻿use soroban_sdk::{token, Address, BytesN, Env, String};
+use crate::storage::structs::car_metadata::CarMetadata;
 use soroban_sdk::{testutils::Events, vec, Val, Vec};
 
 pub(crate) fn create_token_contract<'a>(
@@ -27,3 +28,14 @@ pub(crate) fn get_contract_events(
 
     contract_events
 }
+
+pub(crate) fn car_metadata(env: &Env) -> CarMetadata {
+    CarMetadata {
+        make: String::from_str(env, "Toyota"),
+        model: String::from_str(env, "Corolla"),
+        year: 2022,
+        seats: 5,
+        plate_hash: BytesN::from_array(env, &[7; 32]),
+        image_uri: String::from_str(env, "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"),
+    }
+}
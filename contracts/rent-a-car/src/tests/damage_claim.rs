@@ -0,0 +1,244 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, BytesN, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, types::{claim_status::ClaimStatus, deposit_status::DepositStatus}},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const AMOUNT: i128 = 4500;
+const DEPOSIT: i128 = 2000;
+const AMOUNT_MINT: i128 = 10_000;
+const INSPECTION_WINDOW: u64 = 7_200;
+const RETURNED_AT: u64 = 3 * 86_400;
+
+fn returned_rental_with_deposit<'a>() -> (ContractTest<'a>, Address, Address, u32) {
+    let test = ContractTest::setup();
+    let env = &test.env;
+
+    let owner = Address::generate(env);
+    let renter = Address::generate(env);
+
+    env.mock_all_auths();
+    test.token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = test.contract.add_car(&test.admin, &owner, &1500_i128, &car_metadata(env));
+    test.contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    test.contract.set_inspection_window(&INSPECTION_WINDOW);
+
+    test.contract.rental(&renter, &car_id, &3, &(AMOUNT + DEPOSIT), &test.token.0.address);
+    let rental_id = test.contract.get_active_rental(&car_id).rental_id;
+
+    env.ledger().set_timestamp(RETURNED_AT);
+    test.contract.return_car(&renter, &car_id);
+
+    (test, owner, renter, rental_id)
+}
+
+#[test]
+pub fn test_file_damage_claim_freezes_deposit() {
+    let (ContractTest { env, contract, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    let claim_amount = 800_i128;
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+
+    contract.file_damage_claim(&owner, &rental_id, &claim_amount, &evidence_hash);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let claim = contract.get_damage_claim(&rental_id);
+    assert_eq!(claim.owner, owner);
+    assert_eq!(claim.renter, renter);
+    assert_eq!(claim.amount, claim_amount);
+    assert_eq!(claim.evidence_hash, evidence_hash);
+    assert_eq!(claim.status, ClaimStatus::Filed);
+    assert_eq!(contract.get_deposit(&rental_id).status, DepositStatus::Claimed);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "damage_claim_filed").as_val(),
+                    owner.clone().into_val(&env),
+                    rental_id.into_val(&env),
+                ],
+                (claim_amount, evidence_hash).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_accept_damage_claim_splits_deposit() {
+    let (ContractTest { env, contract, token, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    let claim_amount = 800_i128;
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+
+    contract.file_damage_claim(&owner, &rental_id, &claim_amount, &evidence_hash);
+    contract.accept_damage_claim(&renter, &rental_id);
+
+    let claim = contract.get_damage_claim(&rental_id);
+    assert_eq!(claim.status, ClaimStatus::Accepted);
+    assert_eq!(claim.owner_award, claim_amount);
+    assert_eq!(contract.get_deposit(&rental_id).status, DepositStatus::Settled);
+
+    // Renter gets the rest of the deposit back, owner can withdraw the claim
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - AMOUNT - claim_amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), AMOUNT + claim_amount);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(deposit_balance, 0);
+    assert_eq!(contract_balance, AMOUNT + claim_amount);
+
+    contract.payout_owner(&owner, &(AMOUNT + claim_amount), &token.0.address);
+    assert_eq!(token.0.balance(&owner), AMOUNT + claim_amount);
+}
+
+#[test]
+pub fn test_contested_claim_resolved_by_arbiter() {
+    let (ContractTest { env, contract, token, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    let arbiter = Address::generate(&env);
+    let owner_amount = 500_i128;
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+
+    contract.set_arbiter(&arbiter);
+    assert_eq!(contract.get_arbiter(), arbiter);
+
+    contract.file_damage_claim(&owner, &rental_id, &1_500_i128, &evidence_hash);
+    contract.contest_damage_claim(&renter, &rental_id);
+    assert_eq!(contract.get_damage_claim(&rental_id).status, ClaimStatus::Contested);
+
+    contract.resolve_dispute(&rental_id, &owner_amount);
+
+    let claim = contract.get_damage_claim(&rental_id);
+    assert_eq!(claim.status, ClaimStatus::Resolved);
+    assert_eq!(claim.owner_award, owner_amount);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - AMOUNT - owner_amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), AMOUNT + owner_amount);
+}
+
+#[test]
+pub fn test_arbiter_can_resolve_unanswered_claim() {
+    let (ContractTest { env, contract, token, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    let arbiter = Address::generate(&env);
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+
+    contract.set_arbiter(&arbiter);
+    contract.file_damage_claim(&owner, &rental_id, &DEPOSIT, &evidence_hash);
+
+    contract.resolve_dispute(&rental_id, &0_i128);
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - AMOUNT);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), AMOUNT);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #26)")]
+pub fn test_file_damage_claim_after_inspection_window_fails() {
+    let (ContractTest { env, contract, .. }, owner, _, rental_id) = returned_rental_with_deposit();
+
+    env.ledger().set_timestamp(RETURNED_AT + INSPECTION_WINDOW);
+
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #27)")]
+pub fn test_file_damage_claim_above_deposit_fails() {
+    let (ContractTest { env, contract, .. }, owner, _, rental_id) = returned_rental_with_deposit();
+
+    contract.file_damage_claim(&owner, &rental_id, &(DEPOSIT + 1), &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_file_damage_claim_by_non_owner_fails() {
+    let (ContractTest { env, contract, .. }, _, _, rental_id) = returned_rental_with_deposit();
+
+    let stranger = Address::generate(&env);
+
+    contract.file_damage_claim(&stranger, &rental_id, &800_i128, &BytesN::from_array(&env, &[7; 32]));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #25)")]
+pub fn test_file_damage_claim_twice_fails() {
+    let (ContractTest { env, contract, .. }, owner, _, rental_id) = returned_rental_with_deposit();
+
+    let evidence_hash = BytesN::from_array(&env, &[7; 32]);
+
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &evidence_hash);
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &evidence_hash);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_release_deposit_under_claim_fails() {
+    let (ContractTest { env, contract, .. }, owner, _, rental_id) = returned_rental_with_deposit();
+
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &BytesN::from_array(&env, &[7; 32]));
+
+    env.ledger().set_timestamp(RETURNED_AT + INSPECTION_WINDOW);
+    contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #28)")]
+pub fn test_accept_contested_claim_fails() {
+    let (ContractTest { env, contract, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &BytesN::from_array(&env, &[7; 32]));
+    contract.contest_damage_claim(&renter, &rental_id);
+    contract.accept_damage_claim(&renter, &rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #23)")]
+pub fn test_resolve_dispute_without_arbiter_fails() {
+    let (ContractTest { env, contract, .. }, owner, renter, rental_id) = returned_rental_with_deposit();
+
+    contract.file_damage_claim(&owner, &rental_id, &800_i128, &BytesN::from_array(&env, &[7; 32]));
+    contract.contest_damage_claim(&renter, &rental_id);
+    contract.resolve_dispute(&rental_id, &400_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_set_admin_as_arbiter_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_arbiter(&admin);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_set_car_owner_as_arbiter_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_arbiter(&owner);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #53)")]
+pub fn test_add_car_owned_by_arbiter_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let arbiter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.set_arbiter(&arbiter);
+    contract.add_car(&admin, &arbiter, &1500_i128, &car_metadata(&env));
+}
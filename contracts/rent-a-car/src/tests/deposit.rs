@@ -0,0 +1,231 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, types::deposit_status::DepositStatus},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+#[test]
+pub fn test_rental_locks_deposit_apart_from_contract_balance() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let deposit_amount = 2000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit_amount);
+
+    let quote = contract.quote_rental(&car_id, &total_days, &token_client.address);
+    assert_eq!(quote.deposit, deposit_amount);
+
+    contract.rental(&renter, &car_id, &total_days, &(amount + deposit_amount), &token_client.address);
+
+    let rental = contract.get_active_rental(&car_id);
+    assert_eq!(rental.deposit, deposit_amount);
+
+    let deposit = contract.get_deposit(&rental.rental_id);
+    assert_eq!(deposit.car_id, car_id);
+    assert_eq!(deposit.renter, renter);
+    assert_eq!(deposit.amount, deposit_amount);
+    assert_eq!(deposit.status, DepositStatus::Held);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, amount);
+    assert_eq!(deposit_balance, deposit_amount);
+    assert_eq!(token_client.balance(&renter), amount_mint - amount - deposit_amount);
+}
+
+#[test]
+pub fn test_deposit_released_after_inspection_window() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let deposit_amount = 2000_i128;
+    let inspection_window = 7_200_u64;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit_amount);
+    contract.set_inspection_window(&inspection_window);
+
+    contract.rental(&renter, &car_id, &total_days, &(amount + deposit_amount), &token_client.address);
+    let rental_id = contract.get_active_rental(&car_id).rental_id;
+
+    env.ledger().set_timestamp(3 * 86_400);
+    contract.return_car(&renter, &car_id);
+
+    let deposit = contract.get_deposit(&rental_id);
+    assert_eq!(deposit.status, DepositStatus::InInspection);
+    assert_eq!(deposit.release_ts, 3 * 86_400 + inspection_window);
+
+    env.ledger().set_timestamp(3 * 86_400 + inspection_window);
+    contract.release_deposit(&rental_id);
+
+    let deposit = contract.get_deposit(&rental_id);
+    assert_eq!(deposit.status, DepositStatus::Released);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token_client.address));
+    assert_eq!(deposit_balance, 0);
+    assert_eq!(token_client.balance(&renter), amount_mint - amount);
+
+    // Rental earnings are untouched by the deposit release
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token_client.address), amount);
+}
+
+#[test]
+pub fn test_deposit_released_on_return_without_inspection_window() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+    let deposit_amount = 2000_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit_amount);
+    contract.set_inspection_window(&0);
+
+    contract.rental(&renter, &car_id, &3, &(amount + deposit_amount), &token_client.address);
+    let rental_id = contract.get_active_rental(&car_id).rental_id;
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_deposit(&rental_id).status, DepositStatus::Released);
+    assert_eq!(token_client.balance(&renter), amount_mint - amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_release_deposit_during_inspection_window_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &2000_i128);
+
+    contract.rental(&renter, &car_id, &3, &6500_i128, &token.0.address);
+    let rental_id = contract.get_active_rental(&car_id).rental_id;
+    contract.return_car(&renter, &car_id);
+
+    contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #21)")]
+pub fn test_release_deposit_while_car_is_rented_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &2000_i128);
+
+    contract.rental(&renter, &car_id, &3, &6500_i128, &token.0.address);
+    let rental_id = contract.get_active_rental(&car_id).rental_id;
+
+    contract.release_deposit(&rental_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #20)")]
+pub fn test_get_deposit_not_found_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_deposit(&0_u32);
+}
+
+#[test]
+pub fn test_set_car_deposit_publishes_event() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &2000_i128);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "deposit_set").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                (0_i128, 2000_i128).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_set_car_deposit_by_stranger_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.set_car_deposit(&stranger, &car_id, &2000_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_set_negative_car_deposit_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.set_car_deposit(&owner, &car_id, &-1_i128);
+}
@@ -0,0 +1,159 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, structs::early_return_policy::EarlyReturnPolicy, types::return_timing::ReturnTiming},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1000;
+const COMMISSION: i128 = 100;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_early_return_refunds_unused_days() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 7 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_early_return_policy(&owner, &car_id, &Some(EarlyReturnPolicy { min_charged_days: 1 }));
+    contract.rental(&renter, &car_id, &7, &(amount + COMMISSION), &token.0.address);
+
+    // Returned during day 2: two days are charged, five are refunded
+    env.ledger().set_timestamp(DAY + DAY / 2);
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let refund = 5 * PRICE_PER_DAY;
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_returned").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (ReturnTiming::Early, refund).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount - COMMISSION + refund);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount - refund);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), COMMISSION);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, amount - refund + COMMISSION);
+    assert_eq!(token.0.balance(&contract.address), contract_balance);
+}
+
+#[test]
+pub fn test_early_return_keeps_minimum_charged_days() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 7 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_early_return_policy(&owner, &car_id, &Some(EarlyReturnPolicy { min_charged_days: 3 }));
+    contract.rental(&renter, &car_id, &7, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(DAY / 2);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - 3 * PRICE_PER_DAY);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 3 * PRICE_PER_DAY);
+}
+
+#[test]
+pub fn test_early_return_without_policy_refunds_nothing() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 7 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &7, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_early_return_policy(&car_id), None);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+}
+
+#[test]
+pub fn test_return_in_last_day_refunds_nothing() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 3 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_early_return_policy(&owner, &car_id, &Some(EarlyReturnPolicy { min_charged_days: 0 }));
+    contract.rental(&renter, &car_id, &3, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY + 1);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+}
+
+#[test]
+pub fn test_clear_early_return_policy() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let policy = EarlyReturnPolicy { min_charged_days: 2 };
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.set_early_return_policy(&admin, &car_id, &Some(policy.clone()));
+    assert_eq!(contract.get_early_return_policy(&car_id), Some(policy));
+
+    contract.set_early_return_policy(&owner, &car_id, &None);
+    assert_eq!(contract.get_early_return_policy(&car_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_change_early_return_policy_while_rented_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_early_return_policy(&owner, &car_id, &Some(EarlyReturnPolicy { min_charged_days: 1 }));
+    contract.rental(&renter, &car_id, &3, &(3 * PRICE_PER_DAY), &token.0.address);
+
+    contract.set_early_return_policy(&owner, &car_id, &None);
+}
@@ -0,0 +1,157 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::contract_balance::read_contract_balance,
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1500;
+const COMMISSION: i128 = 100;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_extend_rental_charges_and_pushes_due_time() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let extra_days = 2_u32;
+    let extra_amount = PRICE_PER_DAY * extra_days as i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &3, &(3 * PRICE_PER_DAY + COMMISSION), &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY);
+    contract.extend_rental(&renter, &car_id, &extra_days);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "extended").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (extra_days, extra_amount, 5 * DAY).into_val(&env)
+            )
+        ]
+    );
+
+    let rental = contract.get_active_rental(&car_id);
+    assert_eq!(rental.total_days_to_rent, 5);
+    assert_eq!(rental.amount, 5 * PRICE_PER_DAY);
+    assert_eq!(rental.due_ts, 5 * DAY);
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - 5 * PRICE_PER_DAY - 2 * COMMISSION);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 2 * COMMISSION);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, 5 * PRICE_PER_DAY + 2 * COMMISSION);
+
+    // The owner is credited with the extended amount at return
+    contract.return_car(&renter, &car_id);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 5 * PRICE_PER_DAY);
+}
+
+#[test]
+pub fn test_extend_rental_up_to_next_reservation() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let next_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+    token.1.mint(&next_renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+    contract.reserve(&next_renter, &car_id, &(4 * DAY), &(5 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.extend_rental(&renter, &car_id, &2);
+
+    assert_eq!(contract.get_active_rental(&car_id).due_ts, 4 * DAY);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_extend_rental_into_reservation_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let next_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+    token.1.mint(&next_renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+    contract.reserve(&next_renter, &car_id, &(4 * DAY), &(5 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    contract.extend_rental(&renter, &car_id, &3);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #43)")]
+pub fn test_extend_overdue_rental_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY + 1);
+    contract.extend_rental(&renter, &car_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_extend_rental_by_zero_days_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    contract.extend_rental(&renter, &car_id, &0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_extend_someone_elses_rental_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    contract.extend_rental(&stranger, &car_id, &1);
+}
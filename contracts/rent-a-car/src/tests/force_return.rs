@@ -0,0 +1,160 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, structs::late_fee_policy::LateFeePolicy, types::{car_status::CarStatus, deposit_status::DepositStatus, late_fee_unit::LateFeeUnit}},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1000;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_owner_reclaims_overdue_car() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    contract.force_return(&owner, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_reclaimed").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                (renter.clone(), 0_u32, 2 * DAY).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+
+    contract.payout_owner(&owner, &amount, &token.0.address);
+    assert_eq!(token.0.balance(&owner), amount);
+}
+
+#[test]
+pub fn test_force_return_takes_late_fee_from_deposit_only() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+    let deposit = 1500_i128;
+    let daily_fee = 1000_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    contract.set_late_fee_policy(&owner, &car_id, &Some(LateFeePolicy { fee: daily_fee, unit: LateFeeUnit::Day, grace_period: 0 }));
+    contract.rental(&renter, &car_id, &2, &(amount + deposit), &token.0.address);
+
+    // Two started days late: the fee is 2000 but only the 1500 deposit can be taken
+    env.ledger().set_timestamp(3 * DAY + 1);
+    contract.force_return(&admin, &car_id);
+
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Settled);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount - deposit);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount + deposit);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(deposit_balance, 0);
+    assert_eq!(contract_balance, amount + deposit);
+    assert_eq!(token.0.balance(&contract.address), contract_balance);
+}
+
+#[test]
+pub fn test_force_return_leaves_remaining_deposit_in_inspection() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let deposit = 1500_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_reclaim_grace_period(&0);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY + deposit), &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY + 1);
+    contract.force_return(&owner, &car_id);
+
+    let held = contract.get_deposit(&0);
+    assert_eq!(held.status, DepositStatus::InInspection);
+    assert_eq!(held.amount, deposit);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_force_return_within_grace_period_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    assert_eq!(contract.get_reclaim_grace_period(), DAY);
+
+    env.ledger().set_timestamp(3 * DAY);
+    contract.force_return(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_force_return_without_rental_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.force_return(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_stranger_cannot_force_return() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    env.ledger().set_timestamp(10 * DAY);
+    contract.force_return(&renter, &car_id);
+}
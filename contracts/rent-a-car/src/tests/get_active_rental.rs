@@ -0,0 +1,52 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
+
+#[test]
+pub fn test_get_active_rental_records_start_and_due_time() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+    let start_ts = 1_700_000_000_u64;
+
+    env.mock_all_auths();
+    env.ledger().set_timestamp(start_ts);
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
+
+    let rental = contract.get_active_rental(&car_id);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.total_days_to_rent, total_days);
+    assert_eq!(rental.amount, amount);
+    assert_eq!(rental.start_ts, start_ts);
+    assert_eq!(rental.due_ts, start_ts + 3 * 86_400);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_get_active_rental_without_active_rental_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.get_active_rental(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_active_rental_car_not_found_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_active_rental(&0_u32);
+}
@@ -1,17 +1,17 @@
 use soroban_sdk::{testutils::Address as _, Address};
-use crate::tests::config::contract::ContractTest;
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
 
 #[test]
 pub fn test_get_admin_available_to_withdraw_default_zero() {
-    let ContractTest { contract, .. } = ContractTest::setup();
+    let ContractTest { contract, token, .. } = ContractTest::setup();
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available, 0);
 }
 
 #[test]
 pub fn test_get_admin_available_to_withdraw_after_rental() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -27,30 +27,31 @@ pub fn test_get_admin_available_to_withdraw_after_rental() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
 
     // Before rental, available should be 0
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available, 0);
 
     // After rental, commission should be available
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available, commission);
 }
 
 #[test]
 pub fn test_get_admin_available_to_withdraw_after_multiple_rentals() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner1 = Address::generate(&env);
     let owner2 = Address::generate(&env);
     let renter1 = Address::generate(&env);
     let renter2 = Address::generate(&env);
     let price_per_day = 1500_i128;
-    let total_days = 3;
+    let total_days1 = 3;
+    let total_days2 = 2;
     let amount1 = 4500_i128;
     let amount2 = 3000_i128;
     let commission = 500_i128;
@@ -63,20 +64,20 @@ pub fn test_get_admin_available_to_withdraw_after_multiple_rentals() {
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner1, &price_per_day);
-    contract.add_car(&owner2, &price_per_day);
-    contract.set_admin_commission(&commission);
+    let car_id1 = contract.add_car(&admin, &owner1, &price_per_day, &car_metadata(&env));
+    let car_id2 = contract.add_car(&admin, &owner2, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
 
-    contract.rental(&renter1, &owner1, &total_days, &amount1);
-    contract.rental(&renter2, &owner2, &total_days, &amount2);
+    contract.rental(&renter1, &car_id1, &total_days1, &(amount1 + commission), &token.0.address);
+    contract.rental(&renter2, &car_id2, &total_days2, &(amount2 + commission), &token.0.address);
 
-    let available = contract.get_admin_available_to_withdraw();
+    let available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available, commission * 2);
 }
 
 #[test]
 pub fn test_get_admin_available_to_withdraw_after_withdraw() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -93,16 +94,16 @@ pub fn test_get_admin_available_to_withdraw_after_withdraw() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
-    let available_before = contract.get_admin_available_to_withdraw();
+    let available_before = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available_before, commission);
 
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&admin, &withdraw_amount, &token.0.address);
 
-    let available_after = contract.get_admin_available_to_withdraw();
+    let available_after = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(available_after, commission - withdraw_amount);
 }
 
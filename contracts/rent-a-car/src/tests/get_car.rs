@@ -0,0 +1,139 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, IntoVal, String, Symbol};
+use crate::{storage::types::car_status::CarStatus, tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}}};
+
+#[test]
+pub fn test_get_car_returns_car_and_metadata() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let metadata = car_metadata(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &metadata);
+    let details = contract.get_car(&car_id);
+
+    assert_eq!(details.car_id, car_id);
+    assert_eq!(details.car.owner, owner);
+    assert_eq!(details.car.price_per_day, price_per_day);
+    assert_eq!(details.car.car_status, CarStatus::Available);
+    assert_eq!(details.metadata, metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_get_car_not_found() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_car(&0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_add_car_with_empty_make_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+    metadata.make = String::from_str(&env, "");
+
+    env.mock_all_auths();
+
+    contract.add_car(&admin, &owner, &1500_i128, &metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_add_car_with_oversized_model_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+    metadata.model = String::from_str(&env, "A model name that is far too long to be stored");
+
+    env.mock_all_auths();
+
+    contract.add_car(&admin, &owner, &1500_i128, &metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_add_car_with_zero_seats_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+    metadata.seats = 0;
+
+    env.mock_all_auths();
+
+    contract.add_car(&admin, &owner, &1500_i128, &metadata);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_add_car_with_invalid_year_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+    metadata.year = 1800;
+
+    env.mock_all_auths();
+
+    contract.add_car(&admin, &owner, &1500_i128, &metadata);
+}
+
+#[test]
+pub fn test_owner_updates_car_metadata() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &metadata);
+
+    metadata.seats = 7;
+    metadata.plate_hash = BytesN::from_array(&env, &[8; 32]);
+
+    contract.update_car_metadata(&owner, &car_id, &metadata);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car(&car_id).metadata, metadata);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_metadata_updated").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                metadata.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #33)")]
+pub fn test_update_car_metadata_validates_fields() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let mut metadata = car_metadata(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &metadata);
+
+    metadata.seats = 0;
+
+    contract.update_car_metadata(&owner, &car_id, &metadata);
+}
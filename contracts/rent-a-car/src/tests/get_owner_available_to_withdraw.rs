@@ -1,20 +1,20 @@
 use soroban_sdk::{testutils::Address as _, Address};
-use crate::tests::config::contract::ContractTest;
+use crate::tests::config::{contract::ContractTest, utils::car_metadata};
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_not_found() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     
     // Should return 0 when car doesn't exist
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available, 0);
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_rented() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -29,33 +29,33 @@ pub fn test_get_owner_available_to_withdraw_car_rented() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Should return 0 when car is rented
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available, 0, "Should return 0 when car is rented");
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_available_no_funds() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, token, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 
     // Should return 0 when car is available but has no funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available, 0, "Should return 0 when no funds available");
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_car_available_with_funds() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -70,20 +70,20 @@ pub fn test_get_owner_available_to_withdraw_car_available_with_funds() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
     
     // Return the car first
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
     // Should return the available amount when car is available and has funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available, amount, "Should return the available amount when car is returned");
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_after_partial_withdraw() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -99,26 +99,26 @@ pub fn test_get_owner_available_to_withdraw_after_partial_withdraw() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
-    contract.return_car(&renter, &owner);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
+    contract.return_car(&renter, &car_id);
 
     // Check available before withdrawal
-    let available_before = contract.get_owner_available_to_withdraw(&owner);
+    let available_before = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available_before, amount);
 
     // Withdraw partial amount
-    contract.payout_owner(&owner, &withdraw_amount);
+    contract.payout_owner(&owner, &withdraw_amount, &token.0.address);
 
     // Check available after withdrawal
-    let available_after = contract.get_owner_available_to_withdraw(&owner);
+    let available_after = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available_after, amount - withdraw_amount, 
         "Should return remaining amount after partial withdrawal");
 }
 
 #[test]
 pub fn test_get_owner_available_to_withdraw_multiple_rentals() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter1 = Address::generate(&env);
@@ -137,18 +137,18 @@ pub fn test_get_owner_available_to_withdraw_multiple_rentals() {
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     
     // First rental
-    contract.rental(&renter1, &owner, &total_days1, &amount1);
-    contract.return_car(&renter1, &owner);
+    contract.rental(&renter1, &car_id, &total_days1, &amount1, &token.0.address);
+    contract.return_car(&renter1, &car_id);
 
     // Second rental
-    contract.rental(&renter2, &owner, &total_days2, &amount2);
-    contract.return_car(&renter2, &owner);
+    contract.rental(&renter2, &car_id, &total_days2, &amount2, &token.0.address);
+    contract.return_car(&renter2, &car_id);
 
     // Should return total accumulated funds
-    let available = contract.get_owner_available_to_withdraw(&owner);
+    let available = contract.get_owner_available_to_withdraw(&owner, &token.0.address);
     assert_eq!(available, amount1 + amount2, 
         "Should return total accumulated funds from multiple rentals");
 }
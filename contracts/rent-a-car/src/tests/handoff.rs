@@ -0,0 +1,495 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::types::{car_status::CarStatus, deposit_status::DepositStatus, rental_outcome::RentalOutcome},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1000;
+const DEPOSIT: i128 = 500;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_handoff_holds_funds_until_owner_confirms_return() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(amount + DEPOSIT), &token.0.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::AwaitingPickup);
+
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+    contract.confirm_pickup(&renter, &car_id);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Rented);
+
+    env.ledger().set_timestamp(2 * DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::AwaitingReturnConfirmation);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 0);
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Held);
+
+    env.ledger().set_timestamp(2 * DAY + 600);
+    contract.confirm_return(&owner, &car_id, &12_450, &60);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::InInspection);
+
+    let handoff = contract.get_handoff(&0);
+    assert_eq!(handoff.car_id, car_id);
+    assert_eq!(handoff.pickup_odometer, 12_000);
+    assert_eq!(handoff.pickup_fuel_level, 80);
+    assert_eq!(handoff.returned_ts, 2 * DAY);
+    assert_eq!(handoff.return_odometer, Some(12_450));
+    assert_eq!(handoff.return_fuel_level, Some(60));
+}
+
+#[test]
+pub fn test_mark_handed_over_emits_event() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&admin, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+
+    contract.mark_handed_over(&admin, &car_id, &500, &100);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events.slice(contract_events.len() - 1..),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_handed_over").as_val(),
+                    car_id.into_val(&env),
+                    admin.clone().into_val(&env),
+                ],
+                (0_u32, 500_u64, 100_u32).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_unconfirmed_return_settles_after_timeout() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_inspection_window(&0);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + DEPOSIT), &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+    contract.confirm_pickup(&renter, &car_id);
+
+    env.ledger().set_timestamp(DAY);
+    contract.return_car(&renter, &car_id);
+
+    env.ledger().set_timestamp(2 * DAY);
+    contract.finalize_return(&car_id);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), PRICE_PER_DAY);
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Released);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - PRICE_PER_DAY);
+
+    let handoff = contract.get_handoff(&0);
+    assert_eq!(handoff.return_odometer, None);
+    assert_eq!(handoff.return_fuel_level, None);
+}
+
+#[test]
+pub fn test_reservation_waits_for_pickup() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.start_reservation(&booking_id);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::AwaitingPickup);
+    assert!(!contract.get_handoff(&0).handed_over);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_finalize_return_before_timeout_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+    contract.confirm_pickup(&renter, &car_id);
+
+    env.ledger().set_timestamp(DAY);
+    contract.return_car(&renter, &car_id);
+
+    env.ledger().set_timestamp(2 * DAY - 1);
+    contract.finalize_return(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #47)")]
+pub fn test_confirm_pickup_before_handover_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+
+    contract.confirm_pickup(&renter, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #47)")]
+pub fn test_return_before_pickup_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+
+    contract.return_car(&renter, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")]
+pub fn test_confirm_return_with_lower_odometer_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &1_000, &50);
+    contract.confirm_pickup(&renter, &car_id);
+    contract.return_car(&renter, &car_id);
+
+    contract.confirm_return(&owner, &car_id, &999, &50);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #45)")]
+pub fn test_mark_handed_over_with_invalid_fuel_level_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+
+    contract.mark_handed_over(&owner, &car_id, &0, &101);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+pub fn test_force_return_of_handed_back_car_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &0, &50);
+    contract.confirm_pickup(&renter, &car_id);
+
+    env.ledger().set_timestamp(3 * DAY);
+    contract.return_car(&renter, &car_id);
+    contract.force_return(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_set_handoff_required_while_rented_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+
+    contract.set_handoff_required(&owner, &car_id, &true);
+}
+
+#[test]
+pub fn test_pickup_never_handed_over_is_cancelled_with_full_refund() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(amount + commission + DEPOSIT), &token.0.address);
+
+    // The commission is held back until the renter has the car
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 0);
+
+    // The owner never hands the car over
+    env.ledger().set_timestamp(DAY);
+    let refund = contract.cancel_pickup(&car_id);
+
+    assert_eq!(refund, amount + commission);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Released);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 0);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 0);
+    assert_eq!(contract.get_rental(&0).outcome, RentalOutcome::Cancelled);
+    assert_eq!(token.0.balance(&contract.address), 0);
+
+    // The car can be rented again
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission + DEPOSIT), &token.0.address);
+}
+
+#[test]
+pub fn test_confirm_pickup_credits_commission() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission), &token.0.address);
+    contract.extend_rental(&renter, &car_id, &1);
+
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+    contract.confirm_pickup(&renter, &car_id);
+
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 2 * commission);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_cancel_pickup_before_deadline_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    env.ledger().set_timestamp(DAY - 1);
+    contract.cancel_pickup(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+pub fn test_cancel_pickup_after_pickup_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+    contract.confirm_pickup(&renter, &car_id);
+
+    env.ledger().set_timestamp(DAY);
+    contract.cancel_pickup(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #48)")]
+pub fn test_cancel_pickup_of_handed_over_car_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY + DEPOSIT), &token.0.address);
+
+    // The renter drives off without confirming the pickup
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+
+    env.ledger().set_timestamp(DAY);
+    contract.cancel_pickup(&car_id);
+}
+
+#[test]
+pub fn test_unconfirmed_pickup_is_finalized_after_timeout() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.set_car_deposit(&owner, &car_id, &DEPOSIT);
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY + commission + DEPOSIT), &token.0.address);
+
+    env.ledger().set_timestamp(DAY / 2);
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+
+    env.ledger().set_timestamp(DAY / 2 + DAY);
+    contract.finalize_pickup(&car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "pickup_finalized").as_val(),
+                    car_id.into_val(&env),
+                ],
+                0_u32.into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Rented);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), commission);
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Held);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #49)")]
+pub fn test_finalize_pickup_before_timeout_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    env.ledger().set_timestamp(DAY / 2);
+    contract.mark_handed_over(&owner, &car_id, &12_000, &80);
+
+    // The renter gets the full timeout from the handover, not from the rental start
+    env.ledger().set_timestamp(DAY);
+    contract.finalize_pickup(&car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #47)")]
+pub fn test_finalize_pickup_before_handover_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_handoff_required(&owner, &car_id, &true);
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    env.ledger().set_timestamp(DAY);
+    contract.finalize_pickup(&car_id);
+}
@@ -0,0 +1,196 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, deposit::read_deposit_balance, structs::late_fee_policy::LateFeePolicy, types::{deposit_status::DepositStatus, late_fee_unit::LateFeeUnit}},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const HOUR: u64 = 3_600;
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1000;
+const AMOUNT_MINT: i128 = 20_000;
+const HOURLY_FEE: i128 = 50;
+
+fn hourly_policy(grace_period: u64) -> Option<LateFeePolicy> {
+    Some(LateFeePolicy { fee: HOURLY_FEE, unit: LateFeeUnit::Hour, grace_period })
+}
+
+#[test]
+pub fn test_late_fee_charged_to_renter_without_deposit() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_late_fee_policy(&owner, &car_id, &hourly_policy(HOUR));
+    contract.rental(&renter, &car_id, &2, &amount, &token.0.address);
+
+    // Three and a half hours late with a one hour grace period: three started hours are charged
+    env.ledger().set_timestamp(2 * DAY + 3 * HOUR + HOUR / 2);
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let fee = 3 * HOURLY_FEE;
+    assert_eq!(
+        contract_events.slice(contract_events.len() - 1..),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "late_fee").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (fee, 0_i128, 0_i128).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount - fee);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount + fee);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, amount + fee);
+}
+
+#[test]
+pub fn test_late_fee_taken_from_deposit_first() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+    let deposit = 1000_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    contract.set_late_fee_policy(&owner, &car_id, &hourly_policy(0));
+    contract.rental(&renter, &car_id, &2, &(amount + deposit), &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY + 4 * HOUR);
+    contract.return_car(&renter, &car_id);
+
+    let fee = 4 * HOURLY_FEE;
+    let held = contract.get_deposit(&0);
+    assert_eq!(held.amount, deposit - fee);
+    assert_eq!(held.status, DepositStatus::InInspection);
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount - deposit);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount + fee);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(deposit_balance, deposit - fee);
+    assert_eq!(contract_balance, amount + fee);
+    assert_eq!(token.0.balance(&contract.address), contract_balance + deposit_balance);
+}
+
+#[test]
+pub fn test_late_fee_above_deposit_charges_renter_for_the_rest() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+    let deposit = 100_i128;
+    let daily_fee = 500_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_late_fee_platform_share(&2_000);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &deposit);
+    contract.set_late_fee_policy(&admin, &car_id, &Some(LateFeePolicy { fee: daily_fee, unit: LateFeeUnit::Day, grace_period: 0 }));
+    contract.rental(&renter, &car_id, &2, &(amount + deposit), &token.0.address);
+
+    env.ledger().set_timestamp(3 * DAY + 1);
+    contract.return_car(&renter, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    let fee = 2 * daily_fee;
+    let platform_share = fee / 5;
+    assert_eq!(
+        contract_events.slice(contract_events.len() - 1..),
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "late_fee").as_val(),
+                    renter.clone().into_val(&env),
+                    car_id.into_val(&env),
+                ],
+                (fee, deposit, platform_share).into_val(&env)
+            )
+        ]
+    );
+
+    assert_eq!(contract.get_deposit(&0).status, DepositStatus::Settled);
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount - fee);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount + fee - platform_share);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), platform_share);
+
+    let deposit_balance = env.as_contract(&contract.address, || read_deposit_balance(&env, &token.0.address));
+    assert_eq!(deposit_balance, 0);
+    assert_eq!(token.0.balance(&contract.address), amount + fee);
+}
+
+#[test]
+pub fn test_return_within_grace_period_has_no_late_fee() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_late_fee_policy(&owner, &car_id, &hourly_policy(2 * HOUR));
+    contract.rental(&renter, &car_id, &2, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(2 * DAY + 2 * HOUR);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(token.0.balance(&renter), AMOUNT_MINT - amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_change_late_fee_policy_while_rented_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(2 * PRICE_PER_DAY), &token.0.address);
+
+    contract.set_late_fee_policy(&owner, &car_id, &hourly_policy(0));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #18)")]
+pub fn test_late_fee_platform_share_above_total_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.set_late_fee_platform_share(&10_001);
+}
@@ -0,0 +1,81 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{storage::types::car_status::CarStatus, tests::config::{contract::ContractTest, utils::car_metadata}};
+
+#[test]
+pub fn test_list_cars_returns_pages_in_id_order() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    for price_per_day in [1000_i128, 2000, 3000, 4000, 5000] {
+        contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    }
+
+    assert_eq!(contract.get_car_count(), 5);
+
+    let first_page = contract.list_cars(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().car_id, 0);
+    assert_eq!(first_page.get(1).unwrap().car.price_per_day, 2000);
+
+    let last_page = contract.list_cars(&4, &2);
+    assert_eq!(last_page.len(), 1);
+    assert_eq!(last_page.get(0).unwrap().car_id, 4);
+    assert_eq!(last_page.get(0).unwrap().metadata, car_metadata(&env));
+
+    assert_eq!(contract.list_cars(&5, &2).len(), 0);
+}
+
+#[test]
+pub fn test_list_cars_on_empty_catalog() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    assert_eq!(contract.get_car_count(), 0);
+    assert_eq!(contract.list_cars(&0, &10).len(), 0);
+    assert_eq!(contract.list_available_cars(&0, &10).len(), 0);
+}
+
+#[test]
+pub fn test_list_cars_caps_page_size() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    for _ in 0..55 {
+        contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    }
+
+    assert_eq!(contract.list_cars(&0, &u32::MAX).len(), 50);
+    assert_eq!(contract.list_cars(&u32::MAX, &u32::MAX).len(), 0);
+}
+
+#[test]
+pub fn test_list_available_cars_skips_unavailable_cars() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let rented_car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    let delisted_car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    let available_car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.rental(&renter, &rented_car_id, &2, &3000_i128, &token.0.address);
+    contract.remove_car(&admin, &delisted_car_id);
+
+    let all_cars = contract.list_cars(&0, &10);
+    assert_eq!(all_cars.len(), 3);
+    assert_eq!(all_cars.get(0).unwrap().car.car_status, CarStatus::Rented);
+    assert_eq!(all_cars.get(1).unwrap().car.car_status, CarStatus::Delisted);
+
+    let available_cars = contract.list_available_cars(&0, &10);
+    assert_eq!(available_cars.len(), 1);
+    assert_eq!(available_cars.get(0).unwrap().car_id, available_car_id);
+}
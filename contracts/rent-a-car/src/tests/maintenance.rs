@@ -0,0 +1,192 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, IntoVal, Symbol};
+use crate::{storage::types::car_status::CarStatus, tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}}};
+
+#[test]
+pub fn test_owner_starts_and_ends_maintenance() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let reason_code = 3_u32;
+    let document_hash = Some(BytesN::from_array(&env, &[9; 32]));
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.start_maintenance(&owner, &car_id, &reason_code, &document_hash);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Maintenance);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "maintenance_started").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                (reason_code, document_hash).into_val(&env)
+            )
+        ]
+    );
+
+    contract.end_maintenance(&owner, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "maintenance_ended").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_admin_can_put_car_in_maintenance() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.start_maintenance(&admin, &car_id, &0_u32, &None);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Maintenance);
+
+    contract.end_maintenance(&admin, &car_id);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+}
+
+#[test]
+pub fn test_maintenance_keeps_owner_earnings() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &3, &amount, &token.0.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.start_maintenance(&owner, &car_id, &1_u32, &None);
+
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+    contract.payout_owner(&owner, &amount, &token.0.address);
+    assert_eq!(token.0.balance(&owner), amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_rental_of_car_under_maintenance_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.start_maintenance(&owner, &car_id, &1_u32, &None);
+
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_start_maintenance_on_rented_car_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token.0.address);
+
+    contract.start_maintenance(&owner, &car_id, &1_u32, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #30)")]
+pub fn test_end_maintenance_on_available_car_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.end_maintenance(&owner, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_stranger_cannot_start_maintenance() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.start_maintenance(&stranger, &car_id, &1_u32, &None);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #29)")]
+pub fn test_remove_car_under_maintenance_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.start_maintenance(&owner, &car_id, &1_u32, &None);
+
+    // Delisting and relisting would otherwise skip end_maintenance
+    contract.remove_car(&admin, &car_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #35)")]
+pub fn test_start_maintenance_with_upcoming_booking_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.reserve(&renter, &car_id, &86_400, &(2 * 86_400), &1500_i128, &token.0.address);
+
+    contract.start_maintenance(&owner, &car_id, &3, &None);
+}
@@ -3,13 +3,35 @@
 pub mod config;
 pub mod initialize;
 pub mod add_car;
+pub mod get_car;
 pub mod get_car_status;
 pub mod rental;
 pub mod remove_car;
 pub mod admin_commission;
+pub mod admin_transfer;
 pub mod withdraw_admin_commission;
 pub mod return_car;
 pub mod get_admin_available_to_withdraw;
 pub mod get_owner_available_to_withdraw;
+pub mod get_active_rental;
+pub mod rental_history;
+pub mod role;
+pub mod storage;
+pub mod late_fee;
+pub mod list_cars;
+pub mod booking;
+pub mod cancel_rental;
+pub mod deposit;
+pub mod early_return;
+pub mod extend_rental;
+pub mod force_return;
+pub mod handoff;
+pub mod damage_claim;
+pub mod maintenance;
+pub mod multi_token;
+pub mod pause;
+pub mod quote_rental;
+pub mod update_car;
+pub mod upgrade;
 mod payout_owner;
 mod auth;
\ No newline at end of file
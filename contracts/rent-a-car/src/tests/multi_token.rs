@@ -0,0 +1,436 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::{contract_balance::read_contract_balance, structs::{car_token_price::CarTokenPrice, commission::CommissionConfig, late_fee_policy::LateFeePolicy}, types::{commission_mode::CommissionMode, late_fee_unit::LateFeeUnit}},
+    tests::config::{contract::ContractTest, utils::{car_metadata, create_token_contract, get_contract_events}},
+};
+
+const PRICE_PER_DAY: i128 = 1000;
+const AMOUNT_MINT: i128 = 10_000;
+const DAY: u64 = 86_400;
+
+fn second_token_price() -> CarTokenPrice {
+    CarTokenPrice {
+        price_per_day: PRICE_PER_DAY,
+        deposit_amount: 0,
+        late_fee: 0,
+    }
+}
+
+#[test]
+pub fn test_allowed_tokens_default_to_constructor_token() {
+    let ContractTest { contract, token, .. } = ContractTest::setup();
+
+    assert_eq!(contract.get_allowed_tokens(), vec![&contract.env, token.0.address.clone()]);
+}
+
+#[test]
+pub fn test_allow_token_successfully() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+
+    contract.allow_token(&second_token.address);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract.get_allowed_tokens(),
+        vec![&env, token.0.address.clone(), second_token.address.clone()]
+    );
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "token_allowed").as_val(),
+                    second_token.address.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    // Allowing the same token twice is a no-op
+    contract.allow_token(&second_token.address);
+    assert_eq!(contract.get_allowed_tokens().len(), 2);
+}
+
+#[test]
+pub fn test_rental_in_second_token_tracks_balances_per_token() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+    let total_days = 3;
+    let amount = PRICE_PER_DAY * total_days as i128;
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, token.0.address.clone(), second_token.address.clone()]);
+
+    contract.rental(&renter, &car_id, &total_days, &amount, &second_token.address);
+
+    let rental_id = contract.get_active_rental(&car_id).rental_id;
+    assert_eq!(contract.get_rental_token(&rental_id), second_token.address);
+    assert_eq!(second_token.balance(&renter), AMOUNT_MINT - amount);
+
+    env.as_contract(&contract.address, || {
+        assert_eq!(read_contract_balance(&env, &second_token.address), amount);
+        assert_eq!(read_contract_balance(&env, &token.0.address), 0);
+    });
+
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &second_token.address), amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), 0);
+
+    contract.payout_owner(&owner, &amount, &second_token.address);
+
+    assert_eq!(second_token.balance(&owner), amount);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &second_token.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_rental_with_token_not_in_allowlist_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &second_token.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_rental_with_token_not_accepted_by_car_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &second_token.address);
+}
+
+#[test]
+pub fn test_set_car_tokens_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+    let tokens = vec![&env, second_token.address.clone()];
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+
+    contract.set_car_tokens(&owner, &car_id, &tokens);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_tokens(&car_id), tokens);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_tokens_updated").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                tokens.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_set_car_tokens_with_token_not_in_allowlist_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_set_car_tokens_empty_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.set_car_tokens(&owner, &car_id, &vec![&env]);
+}
+
+#[test]
+pub fn test_disallowed_token_can_still_be_withdrawn() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.allow_token(&second_token.address);
+    contract.set_token_commission(&admin, &second_token.address, &CommissionConfig {
+        mode: CommissionMode::Flat,
+        flat_fee: commission,
+        bps: 0,
+        min_fee: 0,
+        max_fee: 0,
+    });
+    assert_eq!(contract.get_token_commission(&second_token.address).flat_fee, commission);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission), &second_token.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.disallow_token(&second_token.address);
+    assert_eq!(contract.get_allowed_tokens(), vec![&env, token.0.address.clone()]);
+
+    contract.payout_owner(&owner, &PRICE_PER_DAY, &second_token.address);
+    contract.withdraw_admin_commission(&admin, &commission, &second_token.address);
+
+    assert_eq!(second_token.balance(&owner), PRICE_PER_DAY);
+    assert_eq!(second_token.balance(&admin), commission);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_extend_rental_in_disallowed_token_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &second_token.address);
+    contract.disallow_token(&second_token.address);
+
+    contract.extend_rental(&renter, &car_id, &1);
+}
+
+#[test]
+pub fn test_reservation_keeps_its_token_when_started() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, token.0.address.clone(), second_token.address.clone()]);
+
+    let booking_id = contract.reserve(&renter, &car_id, &DAY, &(2 * DAY), &PRICE_PER_DAY, &second_token.address);
+    assert_eq!(contract.get_booking_token(&booking_id), second_token.address);
+
+    env.ledger().set_timestamp(DAY);
+    let rental_id = contract.start_reservation(&booking_id);
+
+    assert_eq!(contract.get_rental_token(&rental_id), second_token.address);
+    assert_eq!(second_token.balance(&renter), AMOUNT_MINT - PRICE_PER_DAY);
+
+    contract.return_car(&renter, &car_id);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &second_token.address), PRICE_PER_DAY);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #14)")]
+pub fn test_allow_address_that_is_not_a_token_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    contract.allow_token(&Address::generate(&env));
+}
+
+#[test]
+pub fn test_rental_in_second_token_is_charged_its_own_price() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+    let price = CarTokenPrice {
+        price_per_day: 300,
+        deposit_amount: 200,
+        late_fee: 0,
+    };
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_admin_commission_bps(&admin, &1_000, &0, &0);
+    contract.allow_token(&second_token.address);
+
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &price);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_token_price_set").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                (second_token.address.clone(), price.clone()).into_val(&env)
+            )
+        ]
+    );
+
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, token.0.address.clone(), second_token.address.clone()]);
+    assert_eq!(contract.get_car_token_price(&car_id, &second_token.address), price);
+    assert_eq!(contract.get_car_token_price(&car_id, &token.0.address).price_per_day, PRICE_PER_DAY);
+
+    // A pure rate commission carries over to every token
+    let quote = contract.quote_rental(&car_id, &2, &second_token.address);
+    assert_eq!(quote.base_price, 600);
+    assert_eq!(quote.commission, 60);
+    assert_eq!(quote.deposit, 200);
+    assert_eq!(contract.quote_rental(&car_id, &2, &token.0.address).base_price, 2 * PRICE_PER_DAY);
+
+    contract.rental(&renter, &car_id, &2, &(quote.total + quote.deposit), &second_token.address);
+    assert_eq!(second_token.balance(&renter), AMOUNT_MINT - quote.total - quote.deposit);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_set_car_tokens_without_price_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let (second_token, _) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_set_car_token_price_in_default_token_fails() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.set_car_token_price(&owner, &car_id, &token.0.address, &second_token_price());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #52)")]
+pub fn test_rental_in_token_without_fixed_commission_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &second_token_price());
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+
+    // The flat fee is an amount of the default token, so it cannot be charged in the second one
+    contract.set_admin_commission(&admin, &100_i128);
+
+    contract.rental(&renter, &car_id, &1, &AMOUNT_MINT, &second_token.address);
+}
+
+#[test]
+pub fn test_late_fee_in_second_token_uses_its_price() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let (second_token, second_token_admin) = create_token_contract(&env, &Address::generate(&env));
+    let late_fee = 30_i128;
+
+    env.mock_all_auths();
+    second_token_admin.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_late_fee_policy(&owner, &car_id, &Some(LateFeePolicy { fee: 500, unit: LateFeeUnit::Day, grace_period: 0 }));
+    contract.allow_token(&second_token.address);
+    contract.set_car_token_price(&owner, &car_id, &second_token.address, &CarTokenPrice { late_fee, ..second_token_price() });
+    contract.set_car_tokens(&owner, &car_id, &vec![&env, second_token.address.clone()]);
+
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &second_token.address);
+
+    env.ledger().set_timestamp(2 * DAY);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(second_token.balance(&renter), AMOUNT_MINT - PRICE_PER_DAY - late_fee);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &second_token.address), PRICE_PER_DAY + late_fee);
+}
@@ -0,0 +1,215 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::types::{car_status::CarStatus, role::Role},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const PRICE_PER_DAY: i128 = 1000;
+
+#[test]
+pub fn test_pause_and_unpause_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+    assert!(!contract.is_paused());
+
+    contract.pause(&admin);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert!(contract.is_paused());
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "paused").as_val(),
+                    admin.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    contract.unpause(&admin);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert!(!contract.is_paused());
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "unpaused").as_val(),
+                    admin.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_pauser_can_pause_and_unpause() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let pauser = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&pauser, &Role::Pauser);
+
+    contract.pause(&pauser);
+    assert!(contract.is_paused());
+
+    contract.unpause(&pauser);
+    assert!(!contract.is_paused());
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_fleet_manager_cannot_pause() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+
+    env.mock_all_auths();
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+
+    contract.pause(&fleet_manager);
+}
+
+#[test]
+pub fn test_return_car_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &(PRICE_PER_DAY * 2), &token.0.address);
+
+    contract.pause(&admin);
+    contract.return_car(&renter, &car_id);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), PRICE_PER_DAY * 2);
+
+    contract.unpause(&admin);
+    contract.payout_owner(&owner, &(PRICE_PER_DAY * 2), &token.0.address);
+    assert_eq!(token.0.balance(&owner), PRICE_PER_DAY * 2);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_rental_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    contract.pause(&admin);
+
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_reserve_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let day = 86_400_u64;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    contract.pause(&admin);
+
+    contract.reserve(&renter, &car_id, &day, &(day * 2), &PRICE_PER_DAY, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_start_reservation_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let day = 86_400_u64;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    let booking_id = contract.reserve(&renter, &car_id, &day, &(day * 2), &PRICE_PER_DAY, &token.0.address);
+    contract.pause(&admin);
+
+    env.ledger().set_timestamp(day);
+    contract.start_reservation(&booking_id);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_extend_rental_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.pause(&admin);
+
+    contract.extend_rental(&renter, &car_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_payout_owner_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.return_car(&renter, &car_id);
+    contract.pause(&admin);
+
+    contract.payout_owner(&owner, &PRICE_PER_DAY, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #51)")]
+pub fn test_withdraw_admin_commission_while_paused() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    contract.set_admin_commission(&admin, &commission);
+    let car_id = contract.add_car(&admin, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission), &token.0.address);
+    contract.pause(&admin);
+
+    contract.withdraw_admin_commission(&admin, &commission, &token.0.address);
+}
@@ -1,6 +1,6 @@
 ﻿use crate::{
-    storage::{car::read_car, contract_balance::read_contract_balance, types::car_status::CarStatus},
-    tests::config::{contract::ContractTest, utils::get_contract_events},
+    storage::{car::read_car, contract_balance::read_contract_balance, owner::read_owner_available_to_withdraw, types::car_status::CarStatus},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
 };
 use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
 
@@ -10,7 +10,7 @@ pub fn test_payout_owner_successfully() {
         env,
         contract,
         token,
-        ..
+        admin, ..
     } = ContractTest::setup();
 
     let owner = Address::generate(&env);
@@ -25,22 +25,22 @@ pub fn test_payout_owner_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Return the car before withdrawing
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
-    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     assert_eq!(contract_balance, amount);
 
-    contract.payout_owner(&owner, &amount);
+    contract.payout_owner(&owner, &amount, &token.0.address);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
-    assert_eq!(car.available_to_withdraw, 0);
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
+    assert_eq!(owner_available, 0);
 
-    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     assert_eq!(contract_balance, 0);
     assert_eq!(
         contract_events,
@@ -52,6 +52,7 @@ pub fn test_payout_owner_successfully() {
                     &env,
                     *Symbol::new(&env, "payout").as_val(),
                     owner.clone().into_val(&env),
+                    token.0.address.clone().into_val(&env),
                 ],
                 amount.into_val(&env)
             )
@@ -60,13 +61,13 @@ pub fn test_payout_owner_successfully() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #13)")]
+#[should_panic(expected = "Error(Contract, #8)")]
 pub fn test_payout_owner_when_car_is_rented_fails() {
     let ContractTest {
         env,
         contract,
         token,
-        ..
+        admin, ..
     } = ContractTest::setup();
 
     let owner = Address::generate(&env);
@@ -81,11 +82,11 @@ pub fn test_payout_owner_when_car_is_rented_fails() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
-    // Try to withdraw while car is still rented (should fail)
-    contract.payout_owner(&owner, &amount);
+    // Try to withdraw while car is still rented (nothing has been credited yet)
+    contract.payout_owner(&owner, &amount, &token.0.address);
 }
 
 #[test]
@@ -94,7 +95,7 @@ pub fn test_payout_owner_after_return_car_successfully() {
         env,
         contract,
         token,
-        ..
+        admin, ..
     } = ContractTest::setup();
 
     let owner = Address::generate(&env);
@@ -109,24 +110,66 @@ pub fn test_payout_owner_after_return_car_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Verify car is rented
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Try to withdraw while rented (should fail)
     // This is tested separately, so we'll just return the car
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 
     // Verify car is now available
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Now withdrawal should succeed
-    contract.payout_owner(&owner, &amount);
+    contract.payout_owner(&owner, &amount, &token.0.address);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
-    assert_eq!(car.available_to_withdraw, 0);
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
+    assert_eq!(owner_available, 0);
+}
+
+#[test]
+pub fn test_payout_owner_pools_earnings_across_cars() {
+    let ContractTest {
+        env,
+        contract,
+        token,
+        admin, ..
+    } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter1 = Address::generate(&env);
+    let renter2 = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    let (token_client, token_admin, _) = token;
+
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter1, &amount_mint);
+    token_admin.mint(&renter2, &amount_mint);
+
+    let car_id1 = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    let car_id2 = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+
+    contract.rental(&renter1, &car_id1, &total_days, &amount, &token_client.address);
+    contract.rental(&renter2, &car_id2, &total_days, &amount, &token_client.address);
+    contract.return_car(&renter1, &car_id1);
+    contract.return_car(&renter2, &car_id2);
+
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token_client.address));
+    assert_eq!(owner_available, amount * 2);
+
+    contract.payout_owner(&owner, &(amount * 2), &token_client.address);
+
+    assert_eq!(token_client.balance(&owner), amount * 2);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, 0);
 }
@@ -0,0 +1,83 @@
+use soroban_sdk::{testutils::Address as _, Address};
+use crate::{storage::structs::rental_quote::RentalQuote, tests::config::contract::ContractTest};
+use crate::tests::config::utils::car_metadata;
+
+#[test]
+pub fn test_quote_rental_without_commission() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let price_per_day = 1500_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+
+    let quote = contract.quote_rental(&car_id, &3, &token.0.address);
+    assert_eq!(
+        quote,
+        RentalQuote {
+            base_price: 4500,
+            commission: 0,
+            total: 4500,
+            deposit: 0,
+        }
+    );
+}
+
+#[test]
+pub fn test_quote_rental_matches_amount_charged() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let commission = 500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+
+    let quote = contract.quote_rental(&car_id, &total_days, &token_client.address);
+    assert_eq!(quote.base_price, 4500);
+    assert_eq!(quote.commission, commission);
+    assert_eq!(quote.total, 5000);
+
+    contract.rental(&renter, &car_id, &total_days, &quote.total, &token_client.address);
+
+    assert_eq!(token_client.balance(&renter), amount_mint - quote.total);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #11)")]
+pub fn test_quote_rental_with_zero_days_fails() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.quote_rental(&car_id, &0, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #16)")]
+pub fn test_quote_rental_overflow_fails() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &i128::MAX, &car_metadata(&env));
+
+    contract.quote_rental(&car_id, &2, &token.0.address);
+}
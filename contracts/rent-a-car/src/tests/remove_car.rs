@@ -1,26 +1,28 @@
 ﻿use soroban_sdk::{testutils::{Address as _, MockAuth, MockAuthInvoke}, IntoVal, Address, Symbol, vec};
-use crate::{storage::car::has_car, tests::config::{contract::ContractTest, utils::get_contract_events}};
+use crate::{storage::{car::has_car, types::car_status::CarStatus}, tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}}};
 
 #[test]
-pub fn test_remove_car_deletes_from_storage() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+pub fn test_remove_car_delists_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
 
     env.mock_all_auths();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     assert!(env.as_contract(&contract.address, || {
-        has_car(&env, &owner)
+        has_car(&env, car_id)
     }));
 
-    contract.remove_car(&owner);
+    contract.remove_car(&admin, &car_id);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    assert!(!env.as_contract(&contract.address, || {
-        has_car(&env, &owner)
+    // The car is delisted rather than deleted
+    assert!(env.as_contract(&contract.address, || {
+        has_car(&env, car_id)
     }));
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Delisted);
 
     assert_eq!(
         contract_events,
@@ -31,7 +33,7 @@ pub fn test_remove_car_deletes_from_storage() {
                 vec![
                     &env,
                     *Symbol::new(&env, "car_removed").as_val(),
-                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
                 ().into_val(&env)
             )
@@ -43,10 +45,130 @@ pub fn test_remove_car_deletes_from_storage() {
 #[test]
 #[should_panic(expected = "Error(Contract, #2)")]
 pub fn test_remove_car_not_found_fails() {
-    let ContractTest { env, contract, .. } = ContractTest::setup();
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+    let car_id = 0_u32;
+
+    env.mock_all_auths();
+
+    contract.remove_car(&admin, &car_id);
+}
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_remove_rented_car_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token.0.address);
+
+    contract.remove_car(&admin, &car_id);
+}
+
+#[test]
+pub fn test_owner_can_withdraw_after_car_is_delisted() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &3, &amount, &token.0.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.remove_car(&admin, &car_id);
+
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), amount);
+    contract.payout_owner(&owner, &amount, &token.0.address);
+    assert_eq!(token.0.balance(&owner), amount);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_rental_of_delisted_car_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.remove_car(&admin, &car_id);
+
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #31)")]
+pub fn test_remove_delisted_car_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
     let owner = Address::generate(&env);
 
     env.mock_all_auths();
 
-    contract.remove_car(&owner);
-}
\ No newline at end of file
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.remove_car(&admin, &car_id);
+    contract.remove_car(&admin, &car_id);
+}
+
+#[test]
+pub fn test_relist_car_successfully() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.remove_car(&admin, &car_id);
+
+    contract.relist_car(&admin, &car_id);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_relisted").as_val(),
+                    car_id.into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    contract.rental(&renter, &car_id, &3, &4500_i128, &token.0.address);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Rented);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #32)")]
+pub fn test_relist_listed_car_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.relist_car(&admin, &car_id);
+}
@@ -1,10 +1,10 @@
 ﻿use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
-use crate::{storage::{car::read_car, contract_balance::read_contract_balance, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
-use crate::tests::config::utils::get_contract_events;
+use crate::{storage::{car::read_car, contract_balance::read_contract_balance, owner::read_owner_available_to_withdraw, rental::read_rental, types::car_status::CarStatus}, tests::config::contract::ContractTest};
+use crate::tests::config::utils::{car_metadata, get_contract_events};
 
 #[test]
 pub fn test_rental_car_successfully() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -19,23 +19,27 @@ pub fn test_rental_car_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint( &renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
     let contract_events = get_contract_events(&env, &contract.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     // When commission is 0 (default), total amount equals rental amount
     assert_eq!(updated_contract_balance, amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(car.owner, owner);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner)).unwrap();
+    // Owner is only credited once the car is returned
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
+    assert_eq!(owner_available, 0);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
     assert_eq!(
@@ -48,9 +52,9 @@ pub fn test_rental_car_successfully() {
                     &env,
                     *Symbol::new(&env, "rented").as_val(),
                     renter.clone().into_val(&env),
-                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                (total_days, amount).into_val(&env)
+                (total_days, amount, token.0.address.clone()).into_val(&env)
             )
         ]
     );
@@ -58,7 +62,7 @@ pub fn test_rental_car_successfully() {
 
 #[test]
 pub fn test_rental_with_admin_commission() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -75,33 +79,38 @@ pub fn test_rental_with_admin_commission() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     
     // Set admin commission
-    contract.set_admin_commission(&commission);
+    contract.set_admin_commission(&admin, &commission);
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &expected_total_amount, &token.0.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     // Contract balance should include both amount and commission
     assert_eq!(updated_contract_balance, expected_total_amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
-    // Owner should receive the full rental amount (commission is added to deposit, not deducted)
-    assert_eq!(car.available_to_withdraw, amount);
 
-    let rental = env.as_contract(&contract.address, || read_rental(&env, &renter, &owner)).unwrap();
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.renter, renter);
     assert_eq!(rental.total_days_to_rent, total_days);
     assert_eq!(rental.amount, amount);
+
+    contract.return_car(&renter, &car_id);
+
+    // Owner should receive the full rental amount (commission is added to deposit, not deducted)
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
+    assert_eq!(owner_available, amount);
 }
 
 #[test]
 pub fn test_rental_with_zero_commission() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -116,25 +125,27 @@ pub fn test_rental_with_zero_commission() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     
     // Set commission to zero (default behavior)
-    contract.set_admin_commission(&0_i128);
+    contract.set_admin_commission(&admin, &0_i128);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     // When commission is 0, total amount equals rental amount
     assert_eq!(updated_contract_balance, amount);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter, &car_id);
+
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
     // Owner should receive full amount when commission is 0
-    assert_eq!(car.available_to_withdraw, amount);
+    assert_eq!(owner_available, amount);
 }
 
 #[test]
 pub fn test_rental_with_commission_added_to_deposit() {
-    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -150,21 +161,93 @@ pub fn test_rental_with_commission_added_to_deposit() {
     let amount_mint = 20_000_i128; // Enough to cover amount + commission
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
     
     // Set commission (even if greater than amount, it just gets added to deposit)
-    contract.set_admin_commission(&commission);
+    contract.set_admin_commission(&admin, &commission);
 
-    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let initial_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     assert_eq!(initial_contract_balance, 0);
 
-    contract.rental(&renter, &owner, &total_days, &amount);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
-    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env));
+    let updated_contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
     // Contract balance should include amount + commission
     assert_eq!(updated_contract_balance, amount + commission);
 
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter, &car_id);
+
+    let owner_available = env.as_contract(&contract.address, || read_owner_available_to_withdraw(&env, &owner, &token.0.address));
     // Owner receives full rental amount
-    assert_eq!(car.available_to_withdraw, amount);
-}
\ No newline at end of file
+    assert_eq!(owner_available, amount);
+}
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_rental_underpayment_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 30;
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &100_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+
+    // Renter offers far less than 30 days at the car's price
+    contract.rental(&renter, &car_id, &total_days, &1_i128, &token.0.address);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #17)")]
+pub fn test_rental_amount_below_commission_and_deposit_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1000_i128;
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &100_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission_bps(&admin, &5_000, &0, &0);
+    contract.set_car_deposit(&owner, &car_id, &3000_i128);
+
+    // Covers the price but not the commission and deposit, 6000 in total
+    contract.rental(&renter, &car_id, &2, &2000_i128, &token.0.address);
+}
+
+#[test]
+pub fn test_rental_overpayment_charges_computed_price() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let offered_amount = 9000_i128;
+    let expected_amount = 4500_i128;
+
+    env.mock_all_auths();
+
+    let (token_client, token_admin, _) = token;
+    let amount_mint = 10_000_i128;
+    token_admin.mint(&renter, &amount_mint);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &offered_amount, &token_client.address);
+
+    let rental = env.as_contract(&contract.address, || read_rental(&env, car_id)).unwrap();
+    assert_eq!(rental.amount, expected_amount);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token_client.address));
+    assert_eq!(contract_balance, expected_amount);
+    assert_eq!(token_client.balance(&renter), amount_mint - expected_amount);
+}
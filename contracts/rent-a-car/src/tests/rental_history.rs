@@ -0,0 +1,146 @@
+use soroban_sdk::{testutils::{Address as _, Ledger}, Address};
+use crate::{
+    storage::{structs::{early_return_policy::EarlyReturnPolicy, late_fee_policy::LateFeePolicy}, types::{late_fee_unit::LateFeeUnit, rental_outcome::RentalOutcome}},
+    tests::config::{contract::ContractTest, utils::car_metadata},
+};
+
+const DAY: u64 = 86_400;
+const PRICE_PER_DAY: i128 = 1000;
+const COMMISSION: i128 = 100;
+const AMOUNT_MINT: i128 = 20_000;
+
+#[test]
+pub fn test_returned_rental_keeps_its_record() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &500);
+    contract.set_early_return_policy(&owner, &car_id, &Some(EarlyReturnPolicy { min_charged_days: 1 }));
+    contract.rental(&renter, &car_id, &3, &(3 * PRICE_PER_DAY + COMMISSION + 500), &token.0.address);
+
+    env.ledger().set_timestamp(DAY / 2);
+    contract.return_car(&renter, &car_id);
+
+    let record = contract.get_rental(&0);
+    assert_eq!(record.car_id, car_id);
+    assert_eq!(record.renter, renter);
+    assert_eq!(record.owner, owner);
+    assert_eq!(record.total_days_to_rent, 3);
+    assert_eq!(record.amount, 3 * PRICE_PER_DAY);
+    assert_eq!(record.commission, COMMISSION);
+    assert_eq!(record.deposit, 500);
+    assert_eq!(record.due_ts, 3 * DAY);
+    assert_eq!(record.returned_ts, DAY / 2);
+    assert_eq!(record.refund, 2 * PRICE_PER_DAY);
+    assert_eq!(record.late_fee, 0);
+    assert_eq!(record.outcome, RentalOutcome::Returned);
+}
+
+#[test]
+pub fn test_reclaimed_rental_records_late_fee() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.set_car_deposit(&owner, &car_id, &1500);
+    contract.set_late_fee_policy(&owner, &car_id, &Some(LateFeePolicy { fee: 1000, unit: LateFeeUnit::Day, grace_period: 0 }));
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + 1500), &token.0.address);
+
+    assert_eq!(contract.get_rental(&0).outcome, RentalOutcome::Active);
+
+    env.ledger().set_timestamp(2 * DAY + 1);
+    contract.force_return(&owner, &car_id);
+
+    let record = contract.get_rental(&0);
+    assert_eq!(record.outcome, RentalOutcome::Reclaimed);
+    assert_eq!(record.returned_ts, 2 * DAY + 1);
+    assert_eq!(record.late_fee, 1500);
+}
+
+#[test]
+pub fn test_extension_updates_the_record() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+
+    contract.set_admin_commission(&admin, &COMMISSION);
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + COMMISSION), &token.0.address);
+    contract.extend_rental(&renter, &car_id, &2);
+
+    let record = contract.get_rental(&0);
+    assert_eq!(record.total_days_to_rent, 3);
+    assert_eq!(record.amount, 3 * PRICE_PER_DAY);
+    assert_eq!(record.commission, 2 * COMMISSION);
+    assert_eq!(record.due_ts, 3 * DAY);
+}
+
+#[test]
+pub fn test_list_rentals_by_renter_and_car() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let other_renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &AMOUNT_MINT);
+    token.1.mint(&other_renter, &AMOUNT_MINT);
+
+    let first_car = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    let second_car = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    contract.rental(&renter, &first_car, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.return_car(&renter, &first_car);
+    contract.rental(&other_renter, &first_car, &1, &PRICE_PER_DAY, &token.0.address);
+    contract.rental(&renter, &second_car, &1, &PRICE_PER_DAY, &token.0.address);
+
+    let renter_rentals = contract.list_rentals_by_renter(&renter, &0, &10);
+    assert_eq!(renter_rentals.len(), 2);
+    assert_eq!(renter_rentals.get(0).unwrap().rental_id, 0);
+    assert_eq!(renter_rentals.get(1).unwrap().rental_id, 2);
+    assert_eq!(renter_rentals.get(1).unwrap().car_id, second_car);
+
+    let car_rentals = contract.list_rentals_by_car(&first_car, &0, &10);
+    assert_eq!(car_rentals.len(), 2);
+    assert_eq!(car_rentals.get(0).unwrap().outcome, RentalOutcome::Returned);
+    assert_eq!(car_rentals.get(1).unwrap().renter, other_renter);
+
+    let second_page = contract.list_rentals_by_car(&first_car, &1, &10);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().rental_id, 1);
+
+    assert_eq!(contract.list_rentals_by_renter(&owner, &0, &10).len(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_get_unknown_rental_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.get_rental(&0);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_list_rentals_of_unknown_car_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.list_rentals_by_car(&0, &0, &10);
+}
@@ -1,10 +1,10 @@
-use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
-use crate::{storage::{car::read_car, rental::has_rental}, storage::types::car_status::CarStatus, tests::config::contract::ContractTest};
-use crate::tests::config::utils::get_contract_events;
+use soroban_sdk::{testutils::{Address as _, Ledger}, vec, Address, IntoVal, Symbol};
+use crate::{storage::{car::read_car, rental::has_rental}, storage::types::{car_status::CarStatus, return_timing::ReturnTiming}, tests::config::contract::ContractTest};
+use crate::tests::config::utils::{car_metadata, get_contract_events};
 
 #[test]
 pub fn test_return_car_successfully() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -19,30 +19,31 @@ pub fn test_return_car_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Verify car is rented
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Verify rental exists
     let rental_exists = env.as_contract(&contract.address, || {
-        has_rental(&env, &renter, &owner)
+        has_rental(&env, car_id)
     });
     assert_eq!(rental_exists, true);
 
-    // Return the car
-    contract.return_car(&renter, &owner);
+    // Return the car on its last day
+    env.ledger().set_timestamp(2 * 86_400 + 3_600);
+    contract.return_car(&renter, &car_id);
     let contract_events = get_contract_events(&env, &contract.address);
 
     // Verify car is available again
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Verify rental is removed
     let rental_exists = env.as_contract(&contract.address, || {
-        has_rental(&env, &renter, &owner)
+        has_rental(&env, car_id)
     });
     assert_eq!(rental_exists, false);
 
@@ -57,9 +58,9 @@ pub fn test_return_car_successfully() {
                     &env,
                     *Symbol::new(&env, "car_returned").as_val(),
                     renter.clone().into_val(&env),
-                    owner.clone().into_val(&env),
+                    car_id.into_val(&env),
                 ],
-                ().into_val(&env)
+                (ReturnTiming::OnTime, 0_i128).into_val(&env)
             )
         ]
     );
@@ -70,19 +71,19 @@ pub fn test_return_car_successfully() {
 pub fn test_return_car_without_rental_fails() {
     let ContractTest { contract, env, .. } = ContractTest::setup();
 
-    let owner = Address::generate(&env);
     let renter = Address::generate(&env);
+    let car_id = 0_u32;
 
     env.mock_all_auths();
 
     // Try to return a car that doesn't exist (will fail with CarNotFound)
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 pub fn test_return_car_when_car_not_rented_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -90,16 +91,16 @@ pub fn test_return_car_when_car_not_rented_fails() {
 
     env.mock_all_auths();
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 
     // Try to return a car that is available, not rented (no rental exists)
-    contract.return_car(&renter, &owner);
+    contract.return_car(&renter, &car_id);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #12)")]
 pub fn test_return_car_self_return_fails() {
-    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let price_per_day = 1500_i128;
@@ -113,17 +114,17 @@ pub fn test_return_car_self_return_fails() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&owner, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&owner, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&owner, &car_id, &total_days, &amount, &token.0.address);
 
     // Owner tries to return their own car (should fail at rental, but test here too)
-    contract.return_car(&owner, &owner);
+    contract.return_car(&owner, &car_id);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #7)")]
 pub fn test_unauthorized_user_cannot_return_car() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -139,16 +140,16 @@ pub fn test_unauthorized_user_cannot_return_car() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
 
     // Unauthorized user tries to return the car (will fail because no rental exists for unauthorized)
-    contract.return_car(&unauthorized, &owner);
+    contract.return_car(&unauthorized, &car_id);
 }
 
 #[test]
 pub fn test_return_car_multiple_rentals_and_returns() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter1 = Address::generate(&env);
@@ -165,26 +166,64 @@ pub fn test_return_car_multiple_rentals_and_returns() {
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
 
     // First rental
-    contract.rental(&renter1, &owner, &total_days, &amount);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.rental(&renter1, &car_id, &total_days, &amount, &token.0.address);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Return car
-    contract.return_car(&renter1, &owner);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter1, &car_id);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 
     // Second rental
-    contract.rental(&renter2, &owner, &total_days, &amount);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.rental(&renter2, &car_id, &total_days, &amount, &token.0.address);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Rented);
 
     // Return car again
-    contract.return_car(&renter2, &owner);
-    let car = env.as_contract(&contract.address, || read_car(&env, &owner)).unwrap();
+    contract.return_car(&renter2, &car_id);
+    let car = env.as_contract(&contract.address, || read_car(&env, car_id)).unwrap();
     assert_eq!(car.car_status, CarStatus::Available);
 }
 
+
+fn return_car_at(returned_ts: u64) -> ReturnTiming {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let price_per_day = 1500_i128;
+    let total_days = 3;
+    let amount = 4500_i128;
+
+    env.mock_all_auths();
+
+    let (_, token_admin, _) = token;
+    token_admin.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &total_days, &amount, &token.0.address);
+
+    env.ledger().set_timestamp(returned_ts);
+    contract.return_car(&renter, &car_id);
+
+    let contract_events = get_contract_events(&env, &contract.address);
+    let (_, _, data) = contract_events.last().unwrap();
+    let (timing, _refund): (ReturnTiming, i128) = data.into_val(&env);
+
+    timing
+}
+
+#[test]
+pub fn test_return_car_reports_early_on_time_and_late() {
+    let due_ts = 3 * 86_400;
+
+    assert_eq!(return_car_at(86_400), ReturnTiming::Early);
+    assert_eq!(return_car_at(due_ts - 86_400), ReturnTiming::Early);
+    assert_eq!(return_car_at(due_ts - 1), ReturnTiming::OnTime);
+    assert_eq!(return_car_at(due_ts), ReturnTiming::OnTime);
+    assert_eq!(return_car_at(due_ts + 1), ReturnTiming::Late);
+}
@@ -0,0 +1,173 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::{
+    storage::types::{car_status::CarStatus, role::Role},
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const PRICE_PER_DAY: i128 = 1000;
+
+#[test]
+pub fn test_fleet_manager_adds_and_removes_cars() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "role_granted").as_val(),
+                    Role::FleetManager.into_val(&env),
+                    fleet_manager.clone().into_val(&env),
+                ],
+                ().into_val(&env)
+            )
+        ]
+    );
+
+    assert!(contract.has_role(&fleet_manager, &Role::FleetManager));
+    assert!(!contract.has_role(&fleet_manager, &Role::Finance));
+
+    let car_id = contract.add_car(&fleet_manager, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.remove_car(&fleet_manager, &car_id);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Delisted);
+
+    contract.relist_car(&fleet_manager, &car_id);
+    assert_eq!(contract.get_car_status(&car_id), CarStatus::Available);
+}
+
+#[test]
+pub fn test_finance_sets_and_withdraws_commission() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let finance = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    contract.grant_role(&finance, &Role::Finance);
+    contract.set_admin_commission(&finance, &commission);
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission), &token.0.address);
+
+    // Finance triggers the withdrawal, the commission still goes to the admin
+    contract.withdraw_admin_commission(&finance, &commission, &token.0.address);
+    assert_eq!(token.0.balance(&finance), 0);
+    assert_eq!(token.0.balance(&admin), commission);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), 0);
+}
+
+#[test]
+pub fn test_finance_withdrawal_goes_to_treasury() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let finance = Address::generate(&env);
+    let treasury = Address::generate(&env);
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let commission = 100_i128;
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000);
+
+    contract.grant_role(&finance, &Role::Finance);
+    contract.set_admin_commission(&finance, &commission);
+    assert_eq!(contract.get_treasury(), admin);
+
+    contract.set_treasury(&treasury);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.get_treasury(), treasury);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "treasury_set").as_val()],
+                treasury.clone().into_val(&env)
+            )
+        ]
+    );
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &1, &(PRICE_PER_DAY + commission), &token.0.address);
+
+    contract.withdraw_admin_commission(&finance, &commission, &token.0.address);
+    assert_eq!(token.0.balance(&treasury), commission);
+    assert_eq!(token.0.balance(&finance), 0);
+    assert_eq!(token.0.balance(&admin), 0);
+}
+
+#[test]
+pub fn test_roles_outlive_renounced_admin() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+    contract.renounce_admin();
+
+    contract.add_car(&fleet_manager, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+    assert_eq!(contract.get_car_count(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_revoked_fleet_manager_cannot_add_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+    contract.revoke_role(&fleet_manager, &Role::FleetManager);
+    assert!(!contract.has_role(&fleet_manager, &Role::FleetManager));
+
+    contract.add_car(&fleet_manager, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_finance_cannot_add_car() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let finance = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.grant_role(&finance, &Role::Finance);
+
+    contract.add_car(&finance, &Address::generate(&env), &PRICE_PER_DAY, &car_metadata(&env));
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_fleet_manager_cannot_withdraw_commission() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let fleet_manager = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    contract.grant_role(&fleet_manager, &Role::FleetManager);
+
+    contract.withdraw_admin_commission(&fleet_manager, &1, &token.0.address);
+}
@@ -0,0 +1,156 @@
+use soroban_sdk::{testutils::{storage::{Instance as _, Persistent as _}, Address as _, Ledger}, vec, Address, BytesN};
+use crate::{
+    constants::{DAY_IN_LEDGERS, INSTANCE_BUMP_AMOUNT, PERSISTENT_BUMP_AMOUNT, PERSISTENT_LIFETIME_THRESHOLD},
+    storage::{
+        structs::{legacy::{LegacyCarRef, OwnerKeyedCar, OwnerKeyedRental}},
+        types::{car_status::CarStatus, rental_outcome::RentalOutcome, storage::{DataKey, LegacyDataKey}},
+    },
+    tests::config::{contract::ContractTest, utils::car_metadata},
+};
+
+const PRICE_PER_DAY: i128 = 1000;
+
+#[test]
+pub fn test_cars_are_kept_in_persistent_storage() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    env.as_contract(&contract.address, || {
+        assert!(!env.storage().instance().has(&DataKey::Car(car_id)));
+        assert_eq!(env.storage().persistent().get_ttl(&DataKey::Car(car_id)), PERSISTENT_BUMP_AMOUNT);
+        assert_eq!(env.storage().persistent().get_ttl(&DataKey::CarMetadata(car_id)), PERSISTENT_BUMP_AMOUNT);
+    });
+}
+
+#[test]
+pub fn test_bump_car_extends_ttl_below_threshold() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    env.as_contract(&contract.address, || {
+        env.storage().instance().extend_ttl(PERSISTENT_BUMP_AMOUNT, PERSISTENT_BUMP_AMOUNT);
+    });
+    env.ledger().set_sequence_number(env.ledger().sequence() + DAY_IN_LEDGERS + 1);
+
+    let ttl = env.as_contract(&contract.address, || env.storage().persistent().get_ttl(&DataKey::Car(car_id)));
+    assert!(ttl < PERSISTENT_LIFETIME_THRESHOLD);
+
+    contract.bump_car(&car_id);
+
+    env.as_contract(&contract.address, || {
+        assert_eq!(env.storage().persistent().get_ttl(&DataKey::Car(car_id)), PERSISTENT_BUMP_AMOUNT);
+        assert_eq!(env.storage().persistent().get_ttl(&DataKey::CarMetadata(car_id)), PERSISTENT_BUMP_AMOUNT);
+    });
+}
+
+#[test]
+pub fn test_instance_ttl_is_extended_on_use() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    env.ledger().set_sequence_number(env.ledger().sequence() + DAY_IN_LEDGERS + 1);
+    contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    let ttl = env.as_contract(&contract.address, || env.storage().instance().get_ttl());
+    assert_eq!(ttl, INSTANCE_BUMP_AMOUNT);
+}
+
+#[test]
+pub fn test_migrate_legacy_cars_converts_owner_keyed_cars() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    let idle_owner = Address::generate(&env);
+    let rented_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = 2 * PRICE_PER_DAY;
+
+    env.mock_all_auths();
+
+    // Simulate the first release: cars keyed by owner, earnings kept on the car and credited at rental time
+    env.as_contract(&contract.address, || {
+        env.storage().instance().set(
+            &LegacyDataKey::Car(idle_owner.clone()),
+            &OwnerKeyedCar { price_per_day: PRICE_PER_DAY, car_status: CarStatus::Available, available_to_withdraw: 700 },
+        );
+        env.storage().instance().set(
+            &LegacyDataKey::Car(rented_owner.clone()),
+            &OwnerKeyedCar { price_per_day: PRICE_PER_DAY, car_status: CarStatus::Rented, available_to_withdraw: amount },
+        );
+        env.storage().instance().set(
+            &LegacyDataKey::Rental(renter.clone(), rented_owner.clone()),
+            &OwnerKeyedRental { total_days_to_rent: 2, amount },
+        );
+    });
+
+    let cars = vec![
+        &env,
+        LegacyCarRef { owner: idle_owner.clone(), renter: None },
+        LegacyCarRef { owner: rented_owner.clone(), renter: Some(renter.clone()) },
+        LegacyCarRef { owner: Address::generate(&env), renter: None },
+    ];
+
+    assert_eq!(contract.migrate_legacy_cars(&cars), 2);
+    assert_eq!(contract.migrate_legacy_cars(&cars), 0);
+
+    let idle_car = contract.get_car(&0);
+    assert_eq!(idle_car.car.owner, idle_owner);
+    assert_eq!(idle_car.car.car_status, CarStatus::Available);
+    assert_eq!(idle_car.metadata.plate_hash, BytesN::from_array(&env, &[0; 32]));
+    assert_eq!(contract.get_owner_available_to_withdraw(&idle_owner, &token.0.address), 700);
+
+    let rental = contract.get_active_rental(&1);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.amount, amount);
+    assert_eq!(rental.due_ts, 2 * 86_400);
+    assert_eq!(contract.get_owner_available_to_withdraw(&rented_owner, &token.0.address), 0);
+
+    env.as_contract(&contract.address, || {
+        assert!(!env.storage().instance().has(&LegacyDataKey::Car(idle_owner.clone())));
+        assert!(!env.storage().instance().has(&LegacyDataKey::Rental(renter.clone(), rented_owner.clone())));
+    });
+
+    // The rental amount reaches the owner once, on return
+    contract.return_car(&renter, &1);
+    assert_eq!(contract.get_owner_available_to_withdraw(&rented_owner, &token.0.address), amount);
+    assert_eq!(contract.get_rental(&rental.rental_id).outcome, RentalOutcome::Returned);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #7)")]
+pub fn test_migrate_rented_legacy_car_without_renter_fails() {
+    let ContractTest { env, contract, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    env.as_contract(&contract.address, || {
+        env.storage().instance().set(
+            &LegacyDataKey::Car(owner.clone()),
+            &OwnerKeyedCar { price_per_day: PRICE_PER_DAY, car_status: CarStatus::Rented, available_to_withdraw: 0 },
+        );
+    });
+
+    contract.migrate_legacy_cars(&vec![&env, LegacyCarRef { owner, renter: None }]);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #2)")]
+pub fn test_bump_unknown_car_fails() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    contract.bump_car(&0);
+}
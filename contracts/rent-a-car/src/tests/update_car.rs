@@ -0,0 +1,117 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, IntoVal, Symbol};
+use crate::tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}};
+
+#[test]
+pub fn test_owner_updates_price_per_day() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let old_price_per_day = 1500_i128;
+    let new_price_per_day = 2000_i128;
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &old_price_per_day, &car_metadata(&env));
+
+    contract.update_car(&owner, &car_id, &new_price_per_day);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(contract.quote_rental(&car_id, &2, &token.0.address).base_price, new_price_per_day * 2);
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "car_updated").as_val(),
+                    car_id.into_val(&env),
+                    owner.clone().into_val(&env),
+                ],
+                (old_price_per_day, new_price_per_day).into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_admin_updates_price_per_day() {
+    let ContractTest { env, contract, admin, token } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.update_car(&admin, &car_id, &1800_i128);
+
+    assert_eq!(contract.quote_rental(&car_id, &1, &token.0.address).base_price, 1800);
+}
+
+#[test]
+pub fn test_update_after_return_applies_to_next_rental() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+    contract.return_car(&renter, &car_id);
+
+    contract.update_car(&owner, &car_id, &1000_i128);
+    contract.rental(&renter, &car_id, &2, &2000_i128, &token.0.address);
+
+    assert_eq!(contract.get_active_rental(&car_id).amount, 2000);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #13)")]
+pub fn test_update_rented_car_fails() {
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+
+    env.mock_all_auths();
+    token.1.mint(&renter, &10_000_i128);
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+    contract.rental(&renter, &car_id, &2, &3000_i128, &token.0.address);
+
+    contract.update_car(&owner, &car_id, &1000_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #6)")]
+pub fn test_update_car_with_non_positive_price_fails() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.update_car(&owner, &car_id, &0_i128);
+}
+
+#[test]
+#[should_panic(expected = "Error(Contract, #19)")]
+pub fn test_stranger_cannot_update_car() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let stranger = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &1500_i128, &car_metadata(&env));
+
+    contract.update_car(&stranger, &car_id, &1000_i128);
+}
@@ -0,0 +1,170 @@
+use soroban_sdk::{testutils::Address as _, vec, Address, BytesN, IntoVal, Symbol};
+use crate::{
+    constants::{CURRENT_SCHEMA_VERSION, LEGACY_SCHEMA_VERSION},
+    storage::{
+        contract_balance::read_contract_balance,
+        structs::legacy::{LegacyCarRef, OwnerKeyedCar, OwnerKeyedRental},
+        types::{car_status::CarStatus, rental_outcome::RentalOutcome, storage::{DataKey, LegacyDataKey}},
+    },
+    tests::config::{contract::ContractTest, utils::{car_metadata, get_contract_events}},
+};
+
+const PRICE_PER_DAY: i128 = 1000;
+const UPGRADE_WASM: &[u8] = include_bytes!("fixtures/contract.wasm");
+
+#[test]
+pub fn test_new_contract_starts_at_current_schema_version() {
+    let ContractTest { contract, .. } = ContractTest::setup();
+
+    assert_eq!(contract.get_schema_version(), CURRENT_SCHEMA_VERSION);
+}
+
+#[test]
+pub fn test_upgrade_successfully() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    let new_wasm_hash = env.deployer().upload_contract_wasm(UPGRADE_WASM);
+    contract.upgrade(&new_wasm_hash);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![
+                    &env,
+                    *Symbol::new(&env, "contract_upgraded").as_val(),
+                    admin.clone().into_val(&env),
+                ],
+                new_wasm_hash.into_val(&env)
+            )
+        ]
+    );
+}
+
+#[test]
+pub fn test_migrate_old_format_state() {
+    let ContractTest { env, contract, token, admin } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+    let idle_owner = Address::generate(&env);
+    let renter = Address::generate(&env);
+    let amount = PRICE_PER_DAY * 2;
+    let earlier_earnings = 300_i128;
+    let commission = 100_i128;
+    let held = amount + earlier_earnings + commission;
+
+    env.mock_all_auths();
+
+    // Simulate a first-release deployment: one token, cars keyed by owner with their earnings on
+    // them, and an active rental keyed by renter and owner
+    env.as_contract(&contract.address, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        env.storage().persistent().set(&LegacyDataKey::ContractBalance, &held);
+        env.storage().instance().set(&LegacyDataKey::AdminAvailableToWithdraw, &commission);
+        env.storage().instance().set(
+            &LegacyDataKey::Car(owner.clone()),
+            &OwnerKeyedCar { price_per_day: PRICE_PER_DAY, car_status: CarStatus::Rented, available_to_withdraw: amount + earlier_earnings },
+        );
+        env.storage().instance().set(
+            &LegacyDataKey::Rental(renter.clone(), owner.clone()),
+            &OwnerKeyedRental { total_days_to_rent: 2, amount },
+        );
+        env.storage().instance().set(
+            &LegacyDataKey::Car(idle_owner.clone()),
+            &OwnerKeyedCar { price_per_day: PRICE_PER_DAY, car_status: CarStatus::Available, available_to_withdraw: 0 },
+        );
+    });
+    token.1.mint(&contract.address, &held);
+
+    assert_eq!(contract.get_schema_version(), LEGACY_SCHEMA_VERSION);
+
+    assert_eq!(contract.migrate(), 2);
+    let contract_events = get_contract_events(&env, &contract.address);
+
+    assert_eq!(
+        contract_events,
+        vec![
+            &env,
+            (
+                contract.address.clone(),
+                vec![&env, *Symbol::new(&env, "schema_migrated").as_val()],
+                (LEGACY_SCHEMA_VERSION, CURRENT_SCHEMA_VERSION, 2_u32).into_val(&env)
+            )
+        ]
+    );
+    assert_eq!(contract.get_schema_version(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(contract.get_admin_available_to_withdraw(&token.0.address), commission);
+
+    // Cars are converted in pages by owner
+    let cars = vec![
+        &env,
+        LegacyCarRef { owner: owner.clone(), renter: Some(renter.clone()) },
+        LegacyCarRef { owner: idle_owner.clone(), renter: None },
+    ];
+    assert_eq!(contract.migrate_legacy_cars(&cars.slice(0..1)), 1);
+    assert_eq!(contract.migrate_legacy_cars(&cars.slice(1..2)), 1);
+
+    let rented_car = contract.get_car(&0);
+    assert_eq!(rented_car.car.owner, owner);
+    assert_eq!(rented_car.car.deposit_amount, 0);
+    assert_eq!(rented_car.metadata.plate_hash, BytesN::from_array(&env, &[0; 32]));
+    assert_eq!(contract.get_car(&1).car.owner, idle_owner);
+
+    let rental = contract.get_active_rental(&0);
+    assert_eq!(rental.rental_id, 0);
+    assert_eq!(rental.renter, renter);
+    assert_eq!(rental.deposit, 0);
+    assert_eq!(contract.get_rental(&0).outcome, RentalOutcome::Active);
+    assert_eq!(contract.get_owner_available_to_withdraw(&owner, &token.0.address), earlier_earnings);
+
+    // Migrated state behaves like any other
+    contract.return_car(&renter, &0);
+    assert_eq!(contract.get_car_status(&0), CarStatus::Available);
+    assert_eq!(contract.get_rental(&0).outcome, RentalOutcome::Returned);
+
+    contract.payout_owner(&owner, &(amount + earlier_earnings), &token.0.address);
+    contract.withdraw_admin_commission(&admin, &commission, &token.0.address);
+    assert_eq!(token.0.balance(&owner), amount + earlier_earnings);
+    assert_eq!(token.0.balance(&contract.address), 0);
+
+    contract.update_car_metadata(&idle_owner, &1, &car_metadata(&env));
+    assert_eq!(contract.get_car(&1).metadata, car_metadata(&env));
+}
+
+#[test]
+pub fn test_migrate_current_state_is_noop() {
+    let ContractTest { env, contract, admin, .. } = ContractTest::setup();
+
+    let owner = Address::generate(&env);
+
+    env.mock_all_auths();
+
+    let car_id = contract.add_car(&admin, &owner, &PRICE_PER_DAY, &car_metadata(&env));
+
+    assert_eq!(contract.migrate(), 0);
+    assert_eq!(contract.get_schema_version(), CURRENT_SCHEMA_VERSION);
+    assert_eq!(contract.get_car(&car_id).metadata, car_metadata(&env));
+}
+
+#[test]
+pub fn test_migrate_twice_converts_nothing_more() {
+    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+
+    env.mock_all_auths();
+
+    env.as_contract(&contract.address, || {
+        env.storage().instance().remove(&DataKey::SchemaVersion);
+        env.storage().persistent().set(&LegacyDataKey::ContractBalance, &500_i128);
+    });
+
+    assert_eq!(contract.migrate(), 1);
+    assert_eq!(contract.migrate(), 0);
+
+    let contract_balance = env.as_contract(&contract.address, || read_contract_balance(&env, &token.0.address));
+    assert_eq!(contract_balance, 500);
+}
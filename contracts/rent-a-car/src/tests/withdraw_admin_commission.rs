@@ -2,6 +2,7 @@ use soroban_sdk::{testutils::Address as _, Address};
 use soroban_sdk::testutils::{MockAuth, MockAuthInvoke};
 use crate::{storage::{admin::read_admin_available_to_withdraw, contract_balance::read_contract_balance}, tests::config::contract::ContractTest};
 use soroban_sdk::IntoVal;
+use crate::tests::config::utils::car_metadata;
 
 #[test]
 pub fn test_withdraw_admin_commission_successfully() {
@@ -22,17 +23,17 @@ pub fn test_withdraw_admin_commission_successfully() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
     let initial_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token.0.address)
     });
     assert_eq!(initial_admin_available, commission);
 
     let initial_contract_balance = env.as_contract(&contract.address, || {
-        read_contract_balance(&env)
+        read_contract_balance(&env, &token.0.address)
     });
 
     contract
@@ -41,19 +42,19 @@ pub fn test_withdraw_admin_commission_successfully() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (withdraw_amount,).into_val(&env),
+                args: (admin.clone(), withdraw_amount, token.0.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&withdraw_amount);
+        .withdraw_admin_commission(&admin, &withdraw_amount, &token.0.address);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token.0.address)
     });
     assert_eq!(updated_admin_available, commission - withdraw_amount);
 
     let updated_contract_balance = env.as_contract(&contract.address, || {
-        read_contract_balance(&env)
+        read_contract_balance(&env, &token.0.address)
     });
     assert_eq!(updated_contract_balance, initial_contract_balance - withdraw_amount);
 }
@@ -76,9 +77,9 @@ pub fn test_withdraw_admin_commission_full_amount() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
     contract
         .mock_auths(&[MockAuth {
@@ -86,14 +87,14 @@ pub fn test_withdraw_admin_commission_full_amount() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (commission,).into_val(&env),
+                args: (admin.clone(), commission, token.0.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&commission);
+        .withdraw_admin_commission(&admin, &commission, &token.0.address);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token.0.address)
     });
     assert_eq!(updated_admin_available, 0);
 }
@@ -101,29 +102,29 @@ pub fn test_withdraw_admin_commission_full_amount() {
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_withdraw_admin_commission_with_zero_amount_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, token, .. } = ContractTest::setup();
     let amount = 0_i128;
 
     env.mock_all_auths();
 
-    contract.withdraw_admin_commission(&amount);
+    contract.withdraw_admin_commission(&admin, &amount, &token.0.address);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #6)")]
 pub fn test_withdraw_admin_commission_with_negative_amount_fails() {
-    let ContractTest { contract, env, .. } = ContractTest::setup();
+    let ContractTest { contract, env, admin, token, .. } = ContractTest::setup();
     let amount = -100_i128;
 
     env.mock_all_auths();
 
-    contract.withdraw_admin_commission(&amount);
+    contract.withdraw_admin_commission(&admin, &amount, &token.0.address);
 }
 
 #[test]
 #[should_panic(expected = "Error(Contract, #8)")]
 pub fn test_withdraw_admin_commission_insufficient_balance_fails() {
-    let ContractTest { contract, env, token, .. } = ContractTest::setup();
+    let ContractTest { contract, env, token, admin, .. } = ContractTest::setup();
 
     let owner = Address::generate(&env);
     let renter = Address::generate(&env);
@@ -140,23 +141,24 @@ pub fn test_withdraw_admin_commission_insufficient_balance_fails() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&admin, &withdraw_amount, &token.0.address);
 }
 
 #[test]
 pub fn test_withdraw_admin_commission_multiple_rentals() {
-    let ContractTest { env, contract, token, .. } = ContractTest::setup();
+    let ContractTest { env, contract, token, admin, .. } = ContractTest::setup();
 
     let owner1 = Address::generate(&env);
     let owner2 = Address::generate(&env);
     let renter1 = Address::generate(&env);
     let renter2 = Address::generate(&env);
     let price_per_day = 1500_i128;
-    let total_days = 3;
+    let total_days1 = 3;
+    let total_days2 = 2;
     let amount1 = 4500_i128;
     let amount2 = 3000_i128;
     let commission = 500_i128;
@@ -169,24 +171,24 @@ pub fn test_withdraw_admin_commission_multiple_rentals() {
     token_admin.mint(&renter1, &amount_mint);
     token_admin.mint(&renter2, &amount_mint);
 
-    contract.add_car(&owner1, &price_per_day);
-    contract.add_car(&owner2, &price_per_day);
-    contract.set_admin_commission(&commission);
+    let car_id1 = contract.add_car(&admin, &owner1, &price_per_day, &car_metadata(&env));
+    let car_id2 = contract.add_car(&admin, &owner2, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
     
-    contract.rental(&renter1, &owner1, &total_days, &amount1);
-    contract.rental(&renter2, &owner2, &total_days, &amount2);
+    contract.rental(&renter1, &car_id1, &total_days1, &(amount1 + commission), &token.0.address);
+    contract.rental(&renter2, &car_id2, &total_days2, &(amount2 + commission), &token.0.address);
 
     let total_commissions = commission * 2;
     let admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token.0.address)
     });
     assert_eq!(admin_available, total_commissions);
 
     let withdraw_amount = 750_i128;
-    contract.withdraw_admin_commission(&withdraw_amount);
+    contract.withdraw_admin_commission(&admin, &withdraw_amount, &token.0.address);
 
     let updated_admin_available = env.as_contract(&contract.address, || {
-        read_admin_available_to_withdraw(&env)
+        read_admin_available_to_withdraw(&env, &token.0.address)
     });
     assert_eq!(updated_admin_available, total_commissions - withdraw_amount);
 }
@@ -210,11 +212,11 @@ pub fn test_withdraw_admin_commission_using_getter() {
     let amount_mint = 10_000_i128;
     token_admin.mint(&renter, &amount_mint);
 
-    contract.add_car(&owner, &price_per_day);
-    contract.set_admin_commission(&commission);
-    contract.rental(&renter, &owner, &total_days, &amount);
+    let car_id = contract.add_car(&admin, &owner, &price_per_day, &car_metadata(&env));
+    contract.set_admin_commission(&admin, &commission);
+    contract.rental(&renter, &car_id, &total_days, &(amount + commission), &token.0.address);
 
-    let initial_admin_available = contract.get_admin_available_to_withdraw();
+    let initial_admin_available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(initial_admin_available, commission, 
         "Expected initial available commission to be {}", commission);
 
@@ -224,13 +226,13 @@ pub fn test_withdraw_admin_commission_using_getter() {
             invoke: &MockAuthInvoke {
                 contract: &contract.address.clone(),
                 fn_name: "withdraw_admin_commission",
-                args: (withdraw_amount,).into_val(&env),
+                args: (admin.clone(), withdraw_amount, token.0.address.clone()).into_val(&env),
                 sub_invokes: &[],
             },
         }])
-        .withdraw_admin_commission(&withdraw_amount);
+        .withdraw_admin_commission(&admin, &withdraw_amount, &token.0.address);
 
-    let updated_admin_available = contract.get_admin_available_to_withdraw();
+    let updated_admin_available = contract.get_admin_available_to_withdraw(&token.0.address);
     assert_eq!(updated_admin_available, commission - withdraw_amount,
         "Expected updated available commission to be {} after withdrawing {}", 
         commission - withdraw_amount, withdraw_amount);